@@ -0,0 +1,69 @@
+//! Lookup of every section by the name, of a file of hundreds of sections:
+//! the name index of `SHEntries::get` against a linear scan.
+//!
+//! `cargo bench --bench section_lookup`
+
+use std::{error::Error, hint::black_box, time::Instant};
+
+use elflib::{
+    view::{EIClass, EIData, EMachine, SHType, SHFLAGS},
+    Elf, ElfBuilder,
+};
+
+
+const SECTIONS: usize = 800;
+const ROUNDS: usize = 200;
+
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let names: Vec<String> =
+        (0..SECTIONS).map(|i| format!(".text.fn_{}", i)).collect();
+
+    let mut builder =
+        ElfBuilder::new(EIClass::Bit64, EIData::LSB).machine(EMachine::X86_64);
+    for name in names.iter() {
+        builder = builder.add_section(
+            name,
+            SHType::PROGBITS,
+            SHFLAGS::from(0x6),
+            16,
+            vec![0xc3],
+        );
+    }
+
+    let elf = Elf::parse(&builder.build()?)?;
+    let shentries = elf.shentries();
+    /* And the null one and `.shstrtab` */
+    assert_eq!(shentries.len(), SECTIONS + 2);
+
+    let indexed = Instant::now();
+    for _ in 0..ROUNDS {
+        for name in names.iter() {
+            black_box(shentries.get(black_box(name)));
+        }
+    }
+    let indexed = indexed.elapsed();
+
+    let scanned = Instant::now();
+    for _ in 0..ROUNDS {
+        for name in names.iter() {
+            black_box(
+                shentries.iter().find(|sh| sh.name() == black_box(name)),
+            );
+        }
+    }
+    let scanned = scanned.elapsed();
+
+    let lookups = (ROUNDS * SECTIONS) as u32;
+    println!(
+        "{} sections, {} lookups: indexed {:?}/lookup, scanned {:?}/lookup \
+         ({:.1}x)",
+        shentries.len(),
+        lookups,
+        indexed / lookups,
+        scanned / lookups,
+        scanned.as_secs_f64() / indexed.as_secs_f64()
+    );
+
+    Ok(())
+}
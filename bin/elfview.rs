@@ -1,9 +1,9 @@
 use std::path::PathBuf;
 
-use clap::Parser;
+use clap::{ArgEnum, Parser};
 use clap_complete::Shell;
 
-use elflib::Elf;
+use elflib::{readelf::SymbolTableKind, Elf};
 
 /// Bas Lang Compiler
 #[derive(Parser)]
@@ -13,16 +13,77 @@ struct Cli {
     #[clap(long = "generate", arg_enum)]
     generator: Option<Shell>,
 
+    /// Display the ELF file header (as `readelf -h`)
+    #[clap(long = "file-header")]
+    file_header: bool,
+
+    /// Display the section headers (as `readelf -S -W`)
+    #[clap(long = "sections")]
+    sections: bool,
+
+    /// Display the program headers and the section to segment mapping
+    /// (as `readelf -l -W`)
+    #[clap(long = "segments")]
+    segments: bool,
+
+    /// Display the symbol table (as `readelf -s -W`)
+    #[clap(long = "symbols")]
+    symbols: bool,
+
+    /// Display the dynamic symbol table
+    #[clap(long = "dyn-symbols")]
+    dyn_symbols: bool,
+
+    /// Demangle the Rust and C++ symbol names of the symbol tables
+    #[clap(long = "demangle")]
+    demangle: bool,
+
+    /// Output format of the whole ELF view
+    #[clap(long = "format", arg_enum, default_value = "debug")]
+    format: Format,
+
     src: PathBuf,
 }
 
+#[derive(Clone, Copy, ArgEnum)]
+enum Format {
+    Debug,
+    Json,
+}
+
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let cli = Cli::parse();
 
     let elf = Elf::load(cli.src)?;
 
-    println!("{:#?}", elf);
+    if cli.file_header {
+        print!("{}", elf.as_readelf_header());
+    } else if cli.sections {
+        print!("{}", elf.format_section_headers());
+    } else if cli.segments {
+        print!("{}", elf.format_program_headers());
+    } else if cli.symbols || cli.dyn_symbols {
+        let format = |which| {
+            if cli.demangle {
+                elf.format_symbols_demangled(which)
+            } else {
+                elf.format_symbols(which)
+            }
+        };
+
+        if cli.dyn_symbols {
+            print!("{}", format(SymbolTableKind::Dynsym));
+        }
+        if cli.symbols {
+            print!("{}", format(SymbolTableKind::Symtab));
+        }
+    } else {
+        match cli.format {
+            Format::Debug => println!("{:#?}", elf),
+            Format::Json => println!("{}", serde_json::to_string(&elf)?),
+        }
+    }
 
     Ok(())
 }
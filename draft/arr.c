@@ -0,0 +1,31 @@
+int arr[10] = {1, 2, 3};
+int counter;
+
+static int square(int x)
+{
+    return x * x;
+}
+
+int sum(int n)
+{
+    int s = 0;
+
+    for (int i = 0; i < n && i < 10; i++) {
+        s += square(arr[i]);
+    }
+
+    return s;
+}
+
+int inc(void)
+{
+    return ++counter;
+}
+
+/* Kept as a non-default (hidden) version in libarr */
+__asm__(".symver sum_v0, sum_v0@ARR_0.9");
+
+int sum_v0(int n)
+{
+    return n;
+}
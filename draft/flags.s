@@ -0,0 +1,26 @@
+# Sections of various flags, for the section flag decoding
+    .section .text.kept, "axR", @progbits
+    ret
+
+    .section .tdata, "awT", @progbits
+    .long 1
+
+    .section .rodata.str, "aMS", @progbits, 1
+    .asciz "merged"
+
+    .section .discard.me, "e", @progbits
+    .byte 0
+
+# Raw flag values, for the readelf flag letters
+    .section .large, "0x10000000", @progbits
+    .byte 0
+    .section .proc.excl, "0xa0000000", @progbits
+    .byte 0
+    .section .unknown.os, "0x00102000", @progbits
+    .byte 0
+    .section .os.mbind, "0x03300002", @progbits
+    .byte 0
+    .section .unknown2, "0x00004008", @progbits
+    .byte 0
+    .section .retain.os, "0x00600000", @progbits
+    .byte 0
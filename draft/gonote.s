@@ -0,0 +1,10 @@
+# Mimic the build id note the Go linker emits
+    .section .note.go.buildid, "a", @note
+    .balign 4
+    .long 4                     # namesz
+    .long 2f - 1f               # descsz
+    .long 4                     # NT_GO_BUILDID
+    .asciz "Go"
+    .byte 0
+1:  .ascii "Zs6iJ1Yw0MgbSTCUPQzE/9yRlEd2Crn3VFmNy7Dd1"
+2:  .balign 4
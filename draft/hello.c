@@ -0,0 +1,37 @@
+#include <stdio.h>
+#include <string.h>
+
+extern int sum(int n);
+
+int main(int argc, char **argv)
+{
+    char buf[64];
+
+    strcpy(buf, argv[0]);
+    printf("%s: %d\n", buf, sum(argc));
+
+    return 0;
+}
+
+static volatile int stage;
+
+static void early(void)
+{
+    stage = 1;
+}
+
+/* Only an executable may have a .preinit_array */
+__attribute__((section(".preinit_array"), used))
+static void (*preinit)(void) = early;
+
+__attribute__((constructor))
+static void init_first(void)
+{
+    stage = 2;
+}
+
+__attribute__((destructor))
+static void fini_last(void)
+{
+    stage = 3;
+}
@@ -0,0 +1,25 @@
+# Functions of Rust (legacy and v0), C++ and C names, for the demangling
+	.text
+	.globl	_Z3addii
+	.type	_Z3addii, @function
+_Z3addii:
+	ret
+	.size	_Z3addii, .-_Z3addii
+
+	.globl	_ZN4core3fmt5write17h0123456789abcdefE
+	.type	_ZN4core3fmt5write17h0123456789abcdefE, @function
+_ZN4core3fmt5write17h0123456789abcdefE:
+	ret
+	.size	_ZN4core3fmt5write17h0123456789abcdefE, .-_ZN4core3fmt5write17h0123456789abcdefE
+
+	.globl	_RNvCs1234_7mycrate3foo
+	.type	_RNvCs1234_7mycrate3foo, @function
+_RNvCs1234_7mycrate3foo:
+	ret
+	.size	_RNvCs1234_7mycrate3foo, .-_RNvCs1234_7mycrate3foo
+
+	.globl	plain_c
+	.type	plain_c, @function
+plain_c:
+	ret
+	.size	plain_c, .-plain_c
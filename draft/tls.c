@@ -0,0 +1,10 @@
+/* Thread-local storage, for the TLS segment */
+
+__thread int counter = 1;
+__thread long slots[8];
+static __thread char tag[3] = "ok";
+
+int bump(void)
+{
+    return ++counter + tag[0] + (int)slots[0];
+}
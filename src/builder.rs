@@ -0,0 +1,581 @@
+#[cfg(feature = "std")]
+use std::io::Write;
+
+use alloc::{borrow::ToOwned, string::String, vec, vec::Vec};
+
+use crate::{
+    ctrl::{narrow, pad_to, serialize_endian},
+    data::{
+        E32Hdr, E32Shdr, E32Sym, E64Hdr, E64Shdr, E64Sym, EIdent,
+        StrTabBuilder,
+    },
+    view::{
+        EIClass, EIData, EMachine, EType, OsAbi, SHType, SymBinding, SymType,
+        SHFLAGS,
+    },
+    ElfError,
+};
+
+
+/// `SHN_LORESERVE`, the section indexes from it are reserved
+const SHN_LORESERVE: usize = 0xff00;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Elf Builder
+
+/// Build an ELF file (without program headers) from scratch.
+///
+/// The section data is laid out after the ELF header in the order
+/// of adding, followed by the `.symtab` and `.strtab` (if there's any
+/// symbol), the `.shstrtab` and the section header table at last.
+#[derive(Debug, Clone)]
+pub struct ElfBuilder {
+    class: EIClass,
+    endian: EIData,
+    osabi: OsAbi,
+    ty: EType,
+    machine: EMachine,
+    entry: u64,
+    flags: u32,
+    sections: Vec<SectionSpec>,
+    symbols: Vec<SymbolSpec>,
+}
+
+#[derive(Debug, Clone)]
+struct SectionSpec {
+    name: String,
+    ty: SHType,
+    flags: SHFLAGS,
+    align: u64,
+
+    /// Only the length of a NOBITS one is used
+    data: Vec<u8>,
+}
+
+#[derive(Debug, Clone)]
+struct SymbolSpec {
+    name: String,
+
+    /// `None` for an undefined symbol
+    section: Option<String>,
+    value: u64,
+    size: u64,
+    ty: SymType,
+    bind: SymBinding,
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Implementations
+
+impl ElfBuilder {
+    /// A relocatable object of no machine, the class other than `Bit32`
+    /// is 64-bit and the data encoding other than `MSB` is little endian.
+    pub fn new(class: EIClass, endian: EIData) -> Self {
+        Self {
+            class,
+            endian,
+            osabi: OsAbi::SysV,
+            ty: EType::REL,
+            machine: EMachine::None,
+            entry: 0,
+            flags: 0,
+            sections: vec![],
+            symbols: vec![],
+        }
+    }
+
+    pub fn osabi(mut self, osabi: OsAbi) -> Self {
+        self.osabi = osabi;
+        self
+    }
+
+    pub fn ty(mut self, ty: EType) -> Self {
+        self.ty = ty;
+        self
+    }
+
+    pub fn machine(mut self, machine: EMachine) -> Self {
+        self.machine = machine;
+        self
+    }
+
+    pub fn entry(mut self, entry: u64) -> Self {
+        self.entry = entry;
+        self
+    }
+
+    pub fn flags(mut self, flags: u32) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Add a section of the raw data, it takes no file space but the
+    /// length of the data as the size if it's NOBITS (e.g. `.bss`).
+    pub fn add_section(
+        mut self,
+        name: &str,
+        ty: SHType,
+        flags: SHFLAGS,
+        align: u64,
+        data: Vec<u8>,
+    ) -> Self {
+        self.sections.push(SectionSpec {
+            name: name.to_owned(),
+            ty,
+            flags,
+            align,
+            data,
+        });
+        self
+    }
+
+    /// Add a symbol of the `.symtab`, defined in the section `section`
+    /// (by the name of an added one) or undefined if it's `None`.
+    ///
+    /// The local symbols are put before the others as the ELF requires.
+    pub fn add_symbol(
+        mut self,
+        name: &str,
+        section: Option<&str>,
+        value: u64,
+        size: u64,
+        ty: SymType,
+        bind: SymBinding,
+    ) -> Self {
+        self.symbols.push(SymbolSpec {
+            name: name.to_owned(),
+            section: section.map(ToOwned::to_owned),
+            value,
+            size,
+            ty,
+            bind,
+        });
+        self
+    }
+
+    /// Lay out and serialize the whole file
+    ///
+    /// The values of a 32-bit file which don't fit their fields are an
+    /// `ElfError::LimitExceeded`.
+    pub fn build(self) -> Result<Vec<u8>, ElfError> {
+        let is_32 = matches!(self.class, EIClass::Bit32);
+        let endian = match self.endian {
+            EIData::MSB => EIData::MSB,
+            _ => EIData::LSB,
+        };
+        let (ehsize, shentsize, symentsize, word) = if is_32 {
+            (52, 40, 16, 4)
+        } else {
+            (64, 64, 24, 8)
+        };
+
+        let shnum =
+            self.sections.len() + if self.symbols.is_empty() { 2 } else { 4 };
+        if shnum >= SHN_LORESERVE {
+            return Err(ElfError::LimitExceeded {
+                what: "section count",
+                declared: shnum,
+                limit: SHN_LORESERVE - 1,
+            });
+        }
+
+        let mut buf = vec![0; ehsize];
+        let mut shstrtab = StrTabBuilder::new();
+        let mut shdrs = vec![E64Shdr::default()];
+
+        for spec in self.sections.iter() {
+            pad_to(&mut buf, spec.align);
+
+            shdrs.push(E64Shdr {
+                name: shstrtab.intern(&spec.name),
+                ty: spec.ty.into(),
+                flags: spec.flags.bits(),
+                addr: 0,
+                offset: buf.len() as u64,
+                size: spec.data.len() as u64,
+                link: 0,
+                info: 0,
+                addr_align: spec.align,
+                ent_size: 0,
+            });
+
+            if !matches!(spec.ty, SHType::NOBITS) {
+                buf.extend_from_slice(&spec.data);
+            }
+        }
+
+        if !self.symbols.is_empty() {
+            let (locals, others): (Vec<_>, Vec<_>) = self
+                .symbols
+                .iter()
+                .partition(|sym| matches!(sym.bind, SymBinding::Local));
+            let first_nonlocal = locals.len() as u32 + 1;
+
+            let mut strtab = StrTabBuilder::new();
+            let mut symtab = vec![0; symentsize];
+
+            for sym in locals.into_iter().chain(others) {
+                let shndx = match sym.section {
+                    Some(ref name) => {
+                        self.sections
+                            .iter()
+                            .position(|spec| &spec.name == name)
+                            .ok_or_else(|| {
+                                ElfError::SectionNotFound(name.clone())
+                            })?
+                            + 1
+                    }
+                    None => 0,
+                } as u16;
+
+                let raw = E64Sym {
+                    name: strtab.intern(&sym.name),
+                    info: sym.bind.to_info_high() | sym.ty.to_info_low(),
+                    other: 0,
+                    shndx,
+                    value: sym.value,
+                    size: sym.size,
+                };
+
+                symtab.extend(if is_32 {
+                    serialize_endian(
+                        &E32Sym {
+                            name: raw.name,
+                            value: narrow("st_value", raw.value)?,
+                            size: narrow("st_size", raw.size)?,
+                            info: raw.info,
+                            other: raw.other,
+                            shndx: raw.shndx,
+                        },
+                        endian,
+                    )
+                } else {
+                    serialize_endian(&raw, endian)
+                });
+            }
+
+            let strtab_idx = shdrs.len() as u32 + 1;
+
+            pad_to(&mut buf, word);
+            shdrs.push(E64Shdr {
+                name: shstrtab.intern(".symtab"),
+                ty: SHType::SYMtab.into(),
+                offset: buf.len() as u64,
+                size: symtab.len() as u64,
+                link: strtab_idx,
+                info: first_nonlocal,
+                addr_align: word,
+                ent_size: symentsize as u64,
+                ..Default::default()
+            });
+            buf.extend_from_slice(&symtab);
+
+            let strtab = strtab.build();
+            shdrs.push(E64Shdr {
+                name: shstrtab.intern(".strtab"),
+                ty: SHType::STRtab.into(),
+                offset: buf.len() as u64,
+                size: strtab.as_bytes().len() as u64,
+                addr_align: 1,
+                ..Default::default()
+            });
+            buf.extend_from_slice(strtab.as_bytes());
+        }
+
+        let shstrndx = shdrs.len() as u16;
+        let name = shstrtab.intern(".shstrtab");
+        let shstrtab = shstrtab.build();
+        shdrs.push(E64Shdr {
+            name,
+            ty: SHType::STRtab.into(),
+            offset: buf.len() as u64,
+            size: shstrtab.as_bytes().len() as u64,
+            addr_align: 1,
+            ..Default::default()
+        });
+        buf.extend_from_slice(shstrtab.as_bytes());
+
+        pad_to(&mut buf, word);
+        let shoff = buf.len() as u64;
+
+        for shdr in shdrs {
+            buf.extend(if is_32 {
+                serialize_endian(
+                    &E32Shdr {
+                        name: shdr.name,
+                        ty: shdr.ty,
+                        flags: narrow("sh_flags", shdr.flags)?,
+                        addr: narrow("sh_addr", shdr.addr)?,
+                        offset: narrow("sh_offset", shdr.offset)?,
+                        size: narrow("sh_size", shdr.size)?,
+                        link: shdr.link,
+                        info: shdr.info,
+                        addr_align: narrow("sh_addralign", shdr.addr_align)?,
+                        ent_size: narrow("sh_entsize", shdr.ent_size)?,
+                    },
+                    endian,
+                )
+            } else {
+                serialize_endian(&shdr, endian)
+            });
+        }
+
+        let ident = EIdent::new(
+            if is_32 { 1 } else { 2 },
+            if matches!(endian, EIData::MSB) { 2 } else { 1 },
+            self.osabi.into(),
+        );
+        let ehdr = E64Hdr {
+            ident,
+            ty: self.ty.into(),
+            machine: self.machine.into(),
+            version: 1,
+            entry: self.entry,
+            phoff: 0,
+            shoff,
+            flags: self.flags,
+            ehsize: ehsize as u16,
+            ph_tab_entry_size: 0,
+            ph_tab_entry_num: 0,
+            sh_tab_entry_size: shentsize,
+            sh_tab_entry_num: shnum as u16,
+            sh_strtab_idx: shstrndx,
+        };
+
+        let ehdr = if is_32 {
+            serialize_endian(
+                &E32Hdr {
+                    ident: ehdr.ident,
+                    ty: ehdr.ty,
+                    machine: ehdr.machine,
+                    version: ehdr.version,
+                    entry: narrow("e_entry", ehdr.entry)?,
+                    phoff: 0,
+                    shoff: narrow("e_shoff", ehdr.shoff)?,
+                    flags: ehdr.flags,
+                    ehsize: ehdr.ehsize,
+                    ph_tab_entry_size: 0,
+                    ph_tab_entry_num: 0,
+                    sh_tab_entry_size: ehdr.sh_tab_entry_size,
+                    sh_tab_entry_num: ehdr.sh_tab_entry_num,
+                    sh_strtab_idx: ehdr.sh_strtab_idx,
+                },
+                endian,
+            )
+        } else {
+            serialize_endian(&ehdr, endian)
+        };
+        buf[..ehsize].copy_from_slice(&ehdr);
+
+        Ok(buf)
+    }
+
+    /// Build the file and write it to `w`
+    #[cfg(feature = "std")]
+    pub fn write<W: Write>(self, w: &mut W) -> Result<(), ElfError> {
+        w.write_all(&self.build()?)?;
+
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use super::ElfBuilder;
+    use crate::{
+        view::{
+            EIClass, EIData, EMachine, EType, SHFlagBit, SHType, SymBinding,
+            SymType, SymValue, SHFLAGS, SID,
+        },
+        Elf, ElfError,
+    };
+
+    fn flags(bits: &[SHFlagBit]) -> SHFLAGS {
+        SHFLAGS::from(bits.iter().fold(0, |acc, bit| acc | bit.bits()))
+    }
+
+    fn sample(class: EIClass, endian: EIData) -> ElfBuilder {
+        let text = vec![0x55, 0x48, 0x89, 0xe5, 0x5d, 0xc3, 0x90, 0x90];
+
+        ElfBuilder::new(class, endian)
+            .machine(EMachine::X86_64)
+            .add_section(
+                ".text",
+                SHType::PROGBITS,
+                flags(&[SHFlagBit::Alloc, SHFlagBit::ExecInstr]),
+                16,
+                text,
+            )
+            .add_section(
+                ".data",
+                SHType::PROGBITS,
+                flags(&[SHFlagBit::Write, SHFlagBit::Alloc]),
+                8,
+                42u64.to_le_bytes().to_vec(),
+            )
+            .add_section(
+                ".bss",
+                SHType::NOBITS,
+                flags(&[SHFlagBit::Write, SHFlagBit::Alloc]),
+                32,
+                vec![0; 64],
+            )
+            .add_symbol(
+                "main",
+                Some(".text"),
+                0,
+                6,
+                SymType::Func,
+                SymBinding::Global,
+            )
+            .add_symbol(
+                "puts",
+                None,
+                0,
+                0,
+                SymType::NoType,
+                SymBinding::Global,
+            )
+            .add_symbol(
+                "counter",
+                Some(".data"),
+                0,
+                8,
+                SymType::Object,
+                SymBinding::Local,
+            )
+    }
+
+    #[test]
+    fn build_round_trip() -> Result<(), Box<dyn Error>> {
+        for (class, endian) in
+            [(EIClass::Bit64, EIData::LSB), (EIClass::Bit32, EIData::MSB)]
+        {
+            let mut bytes = vec![];
+            sample(class, endian).write(&mut bytes)?;
+
+            let elf = Elf::parse(&bytes)?;
+            assert_eq!(elf.ehdr().ident().class(), class);
+            assert_eq!(elf.ehdr().ident().data(), endian);
+            assert!(matches!(elf.ehdr().ty(), EType::REL));
+            assert_eq!(*elf.ehdr().machine(), EMachine::X86_64);
+            elf.validate().map_err(|issues| format!("{:?}", issues))?;
+
+            let names: Vec<_> = elf
+                .shentries()
+                .iter()
+                .map(|sh| sh.name().as_str())
+                .collect();
+            assert_eq!(
+                names,
+                [
+                    "",
+                    ".text",
+                    ".data",
+                    ".bss",
+                    ".symtab",
+                    ".strtab",
+                    ".shstrtab"
+                ]
+            );
+
+            let text = elf.shentries().get(".text").unwrap();
+            assert_eq!(text.offset().0 % 16, 0);
+            assert_eq!(
+                elf.section_data_raw(".text"),
+                Some(&[0x55, 0x48, 0x89, 0xe5, 0x5d, 0xc3, 0x90, 0x90][..])
+            );
+            assert_eq!(
+                elf.section_data_raw(".data"),
+                Some(&42u64.to_le_bytes()[..])
+            );
+            assert_eq!(*elf.shentries().get(".bss").unwrap().size(), 64);
+            assert_eq!(elf.section_data_raw(".bss"), None);
+
+            /* The local one goes first */
+            let syms: Vec<_> =
+                elf.symtab().iter().map(|sym| sym.name().as_str()).collect();
+            assert_eq!(syms, ["", "counter", "main", "puts"]);
+            assert_eq!(*elf.shentries().get(".symtab").unwrap().info(), 2);
+
+            let main = elf.symbol("main").unwrap();
+            assert_eq!(main.section_name(&elf), Some(".text"));
+            assert_eq!(*main.value(), SymValue::SectionOffset(0));
+            assert_eq!(*main.size(), 6);
+            assert_eq!(*main.ty(), SymType::Func);
+            assert_eq!(*elf.symbol("puts").unwrap().shndx(), SID::Undef);
+            assert_eq!(
+                *elf.symbol("counter").unwrap().bind(),
+                SymBinding::Local
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn build_without_symbols() -> Result<(), Box<dyn Error>> {
+        let bytes = ElfBuilder::new(EIClass::Bit64, EIData::LSB)
+            .ty(EType::EXEC)
+            .entry(0x40_1000)
+            .build()?;
+        let elf = Elf::parse(&bytes)?;
+
+        assert!(matches!(elf.ehdr().ty(), EType::EXEC));
+        assert_eq!(elf.ehdr().entry().0, 0x40_1000);
+        assert_eq!(elf.shentries().len(), 2);
+        assert!(elf.symtab().is_empty());
+
+        let err = sample(EIClass::Bit64, EIData::LSB)
+            .add_symbol(
+                "x",
+                Some(".nosuch"),
+                0,
+                0,
+                SymType::NoType,
+                SymBinding::Global,
+            )
+            .build()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "section `.nosuch` not found");
+
+        /* Out of the 32-bit fields, rather than truncated */
+        let err = sample(EIClass::Bit32, EIData::LSB)
+            .entry(0x1_0000_0000)
+            .build()
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ElfError::LimitExceeded {
+                what: "e_entry",
+                ..
+            }
+        ));
+
+        let err = sample(EIClass::Bit32, EIData::LSB)
+            .add_symbol(
+                "big",
+                Some(".data"),
+                0,
+                1 << 32,
+                SymType::Object,
+                SymBinding::Global,
+            )
+            .build()
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ElfError::LimitExceeded {
+                what: "st_size",
+                ..
+            }
+        ));
+
+        Ok(())
+    }
+}
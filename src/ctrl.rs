@@ -1,114 +1,480 @@
+use core::{
+    any::type_name, cmp::Ordering, fmt::Debug, mem::size_of, ops::Deref,
+};
+#[cfg(feature = "std")]
 use std::{
-    error::Error, fmt::Debug, fs::File, io::ErrorKind, mem::size_of,
-    path::Path,
+    collections::HashMap, error::Error, fs::File, io::Read, path::Path,
+};
+
+/* No hasher in `alloc`, the ordered map serves as well */
+#[cfg(not(feature = "std"))]
+use alloc::collections::BTreeMap as HashMap;
+use alloc::{
+    borrow::{Cow, ToOwned},
+    format,
+    string::String,
+    sync::Arc,
+    vec,
+    vec::Vec,
 };
 
-use bincode::{options, Options};
 use getset::Getters;
+#[cfg(feature = "std")]
 use memmap2::{Mmap, MmapOptions};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
 
 use crate::{
-    data::{E64Hdr, E64Shdr, E64Sym, EIdent, StrTab},
+    data::{
+        E32Dyn, E32Hdr, E32Phdr, E32Rela, E32Shdr, E32Sym, E64Dyn, E64Hdr,
+        E64Phdr, E64Rela, E64Shdr, E64Sym, E64Verdaux, E64Verdef, E64Vernaux,
+        E64Verneed, EIdent, Raw, SectionData, StrTab, StrTabBuilder,
+    },
+    error::Format,
     view::{
-        EHdrView, EIClass, EIData, EIdentView, EType, Hex64, MagicNums,
-        SHEntries, SHType, SHdrView, SymBinding, SymTab, SymType, SymValue,
-        SymView, SymVisi, SHFLAGS, SID,
+        CoreInfo, CoreThread, DynEntry, DynTag, EHdrView, EIClass, EIData,
+        EIdentView, EMachine, EType, EhFrameHdr, FileKind, GnuHashTable,
+        GnuProperties, Hex64, MagicNums, NoteView, PFlagBit, PHdrView, PhType,
+        PltEntry, RelaView, RelocX86_64, RelroStatus, SHEntries, SHFlagBit,
+        SHType, SHdrView, SymBinding, SymTab, SymType, SymValue, SymView,
+        SymVisi, SysvHashTable, TlsInfo, VerDefView, VerNeedAuxView,
+        VerNeedView, VersionInfo, PFLAGS, SHFLAGS, SID,
     },
+    ElfError,
 };
 
 
-#[derive(Clone, Getters)]
+/// Serialized as the decoded views, the string tables are resolved into
+/// the names already
+#[derive(Clone, Getters, Serialize)]
 #[getset(get = "pub")]
 pub struct Elf {
     ehdr: EHdrView,
 
+    /// Program Header Table (segments)
+    phentries: Vec<PHdrView>,
+
     /// Section Name String Table
+    #[serde(skip)]
     shstrtab: StrTab,
     shentries: SHEntries,
 
     /// Symbol Table Entry Related String Table
+    #[serde(skip)]
     strtab: StrTab,
     symtab: SymTab,
 
-    dynsym: SymTab
+    /// Dynamic Symbol and Dynamic Section Related String Table
+    #[serde(skip)]
+    dynstr: StrTab,
+    dynsym: SymTab,
+
+    /// Dynamic section entries, without the terminating NULL
+    dynamic: Vec<DynEntry>,
+
+    /// Version index of each dynsym entry (`.gnu.version`)
+    versym: Vec<u16>,
+
+    /// Version definitions (`.gnu.version_d`)
+    verdef: Vec<VerDefView>,
+
+    /// Version requirements (`.gnu.version_r`)
+    verneed: Vec<VerNeedView>,
+
+    /// Hash table of the dynamic symbols (`.gnu.hash`)
+    gnu_hash: Option<GnuHashTable>,
+
+    /// Hash table of the dynamic symbols (SysV `.hash`)
+    sysv_hash: Option<SysvHashTable>,
+
+    /// Entries of each RELA section, by section name
+    relocations: Vec<(String, Vec<RelaView>)>,
+
+    /// Raw file image, retained for post-load byte access
+    #[getset(skip)]
+    #[serde(skip)]
+    data: Backing,
 }
 
-macro_rules! bincode_options {
-    () => {
-        options().with_fixint_encoding()
-    };
+/// Limits checked while loading, against resource exhaustion
+/// by a crafted file (e.g. a huge declared `e_shnum` of a tiny file).
+///
+/// `None` means no limit, however a declared table is always checked to
+/// fit in the file.
+#[derive(Debug, Default, Clone)]
+pub struct LoadOptions {
+    pub max_sections: Option<usize>,
+
+    /// Per symbol table
+    pub max_symbols: Option<usize>,
+
+    /// In bytes
+    pub max_file_size: Option<usize>,
+
+    /// Leave the symbol names unresolved, see [`Elf::parse_lazy`]
+    pub lazy_sym_names: bool,
+}
+
+/// Sections dropped by [`Elf::strip`], all of them by default.
+///
+/// An allocated section is never dropped, nor the one still referred to
+/// by a kept section (e.g. the `.symtab` of the relocations of an object).
+#[derive(Debug, Clone)]
+pub struct StripOptions {
+    /// `.symtab` and `.strtab`
+    pub symbols: bool,
+
+    /// `.debug_*` and `.zdebug_*` (and the relocations of them)
+    pub debug: bool,
+
+    /// `.comment`
+    pub comment: bool,
+}
+
+/// Parsed file in shared ownership, see [`Elf::into_shared`].
+///
+/// Cloning it bumps a reference count instead of copying the views and
+/// the image, the getters of `Elf` are reached through `Deref`.
+#[derive(Clone)]
+pub struct SharedElf(Arc<Elf>);
+
+/// Storage of the raw file image
+enum Backing {
+    #[cfg(feature = "std")]
+    Mmap(Mmap),
+    Owned(Vec<u8>),
 }
 
+/// Raw structures of an ELF class, the 32-bit ones are widened into
+/// the 64-bit ones (or the views) right after being read.
+trait ElfClass {
+    type Hdr: Raw + Into<EHdrView>;
+    type Phdr: Raw + Into<PHdrView>;
+    type Shdr: Raw + Into<E64Shdr>;
+    type Sym: Raw + Into<E64Sym>;
+    type Dyn: Raw + Into<E64Dyn>;
+    type Rela: Raw + Into<E64Rela>;
+}
+
+struct Class32;
+struct Class64;
+
+/// `e_phnum` of too many segments, the count is in the section 0
+const PN_XNUM: u16 = 0xffff;
+
+/// Note type of the GNU build ID (owner "GNU")
+const NT_GNU_BUILD_ID: u32 = 3;
+
+/// Note type of the Go build ID (owner "Go")
+const NT_GO_BUILDID: u32 = 4;
+
+/// Note type of the program properties (owner "GNU")
+const NT_GNU_PROPERTY_TYPE_0: u32 = 5;
+
+/// Property of the AArch64 features all the inputs have (BTI, PAC)
+const GNU_PROPERTY_AARCH64_FEATURE_1_AND: u32 = 0xc000_0000;
+
+/// Property of the x86 features all the inputs have (IBT, SHSTK)
+const GNU_PROPERTY_X86_FEATURE_1_AND: u32 = 0xc000_0002;
+
+
+/// `DW_EH_PE_omit`, the pointer is absent
+const DW_EH_PE_OMIT: u8 = 0xff;
+
+/// Note type of the thread status of a core dump (owner "CORE")
+const NT_PRSTATUS: u32 = 1;
+
+/// Note type of the process info of a core dump (owner "CORE")
+const NT_PRPSINFO: u32 = 3;
+
+/// `user_regs_struct` of x86-64, `pr_reg` of `NT_PRSTATUS`
+const X86_64_REGS: [&str; 27] = [
+    "r15", "r14", "r13", "r12", "rbp", "rbx", "r11", "r10", "r9", "r8", "rax",
+    "rcx", "rdx", "rsi", "rdi", "orig_rax", "rip", "cs", "eflags", "rsp",
+    "ss", "fs_base", "gs_base", "ds", "es", "fs", "gs",
+];
+
 
 impl Elf {
+    #[cfg(feature = "std")]
     pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn Error>> {
-        let config = bincode_options!();
         let reader = File::open(path)?;
 
         let mmap = unsafe { MmapOptions::new().map(&reader)? };
 
-        let eident: EIdent =
-            config.deserialize(&mmap[..size_of::<EIdent>()])?;
+        Ok(Self::load_from_backing(
+            Backing::Mmap(mmap),
+            &LoadOptions::default(),
+        )?)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn load_with_options<P: AsRef<Path>>(
+        path: P,
+        opts: &LoadOptions,
+    ) -> Result<Self, Box<dyn Error>> {
+        let reader = File::open(path)?;
+
+        let mmap = unsafe { MmapOptions::new().map(&reader)? };
+
+        Ok(Self::load_from_backing(Backing::Mmap(mmap), opts)?)
+    }
+
+    /// Move into shared ownership, for the same parsed file handed to
+    /// many threads or tasks
+    pub fn into_shared(self) -> SharedElf {
+        SharedElf(Arc::new(self))
+    }
+
+    /// Parse an ELF image already in memory, the bytes are copied.
+    ///
+    /// It never panics on arbitrary input: a malformed header or table
+    /// is an `Err`, while a broken optional part (a string table, the
+    /// versioning etc.) is left empty. The corpus under
+    /// `tests/fuzz_corpus` pins this down.
+    pub fn parse(data: &[u8]) -> Result<Self, ElfError> {
+        Self::parse_with_options(data, &LoadOptions::default())
+    }
+
+    /// Parse as `parse` but without copying out the symbol names, which
+    /// is most of the allocation for a large symbol table.
+    ///
+    /// The names of the symbols are left empty, read them by
+    /// [`Elf::resolve_sym_name`]. The section names are resolved still.
+    pub fn parse_lazy(data: &[u8]) -> Result<Self, ElfError> {
+        let opts = LoadOptions {
+            lazy_sym_names: true,
+            ..Default::default()
+        };
+
+        Self::parse_with_options(data, &opts)
+    }
+
+    pub fn parse_with_options(
+        data: &[u8],
+        opts: &LoadOptions,
+    ) -> Result<Self, ElfError> {
+        Self::load_owned(data.to_vec(), opts)
+    }
+
+    /// Read all of `reader` (e.g. a socket or a decompression stream) and
+    /// parse it as `parse`.
+    ///
+    /// The stream is buffered fully, there's no streaming parse since
+    /// the tables are located by offsets all over the image.
+    #[cfg(feature = "std")]
+    pub fn from_reader<R: Read>(mut reader: R) -> Result<Self, ElfError> {
+        let mut data = vec![];
+        reader.read_to_end(&mut data)?;
+
+        Self::load_owned(data, &LoadOptions::default())
+    }
+
+    fn load_owned(
+        data: Vec<u8>,
+        opts: &LoadOptions,
+    ) -> Result<Self, ElfError> {
+        if let Some(limit) = opts.max_file_size {
+            check_limit("file size", data.len(), limit)?;
+        }
+
+        Self::load_from_backing(Backing::Owned(data), opts)
+    }
+
+    /// Quick check of the identification and the header tables, without
+    /// parsing: magic, class, data encoding, version, and that the program
+    /// and section header tables lie inside `bytes`.
+    pub fn is_valid_elf(bytes: &[u8]) -> bool {
+        if !bytes.starts_with(b"\x7fELF") || bytes.len() < 16 {
+            return false;
+        }
+
+        let data = match bytes[5] {
+            1 => EIData::LSB,
+            2 => EIData::MSB,
+            _ => return false,
+        };
+
+        if bytes[6] != 1 {
+            return false;
+        }
+
+        let raw = SectionData::new(bytes, data);
+
+        /* Header size, the smallest program and section header entries */
+        let (is_64, hdr_size, min_phentsize, min_shentsize) = match bytes[4] {
+            1 => (false, 52, 32, 40),
+            2 => (true, 64, 56, 64),
+            _ => return false,
+        };
+
+        if bytes.len() < hdr_size {
+            return false;
+        }
+
+        let (phoff, shoff) = if is_64 {
+            (raw.read_u64(32), raw.read_u64(40))
+        } else {
+            (
+                raw.read_u32(28).map(u64::from),
+                raw.read_u32(32).map(u64::from),
+            )
+        };
+        let (phoff, shoff) = (phoff.unwrap_or(0), shoff.unwrap_or(0));
+
+        /* e_ehsize, e_phentsize, e_phnum, e_shentsize, e_shnum */
+        let half = |n: usize| raw.read_u16(hdr_size - 12 + 2 * n).unwrap_or(0);
+        let (ehsize, phentsize, phnum) = (half(0), half(1), half(2));
+        let (shentsize, shnum) = (half(3), half(4));
+
+        if (ehsize as usize) < hdr_size {
+            return false;
+        }
+
+        let table_fits = |off: u64, entsize: u16, num: u16, min: u16| {
+            if off == 0 {
+                return true;
+            }
+
+            /* The extended numbering keeps the count in the first entry */
+            let num = u64::from(num.max(1));
+
+            entsize >= min
+                && u64::from(entsize)
+                    .checked_mul(num)
+                    .and_then(|size| size.checked_add(off))
+                    .is_some_and(|end| end <= bytes.len() as u64)
+        };
+
+        (phnum == 0 || table_fits(phoff, phentsize, phnum, min_phentsize))
+            && table_fits(shoff, shentsize, shnum, min_shentsize)
+    }
+
+    fn load_from_backing(
+        data: Backing,
+        opts: &LoadOptions,
+    ) -> Result<Self, ElfError> {
+        if let Some(limit) = opts.max_file_size {
+            check_limit("file size", data.len(), limit)?;
+        }
+
+        if !data.starts_with(b"\x7fELF") {
+            return Err(match Format::detect(&data) {
+                Some(detected) => ElfError::NotElf { detected },
+                None => ElfError::BadMagic,
+            });
+        }
+
+        let ident_raw =
+            table_entry("identification", &data, 0, 0, size_of::<EIdent>())?;
+        let eident: EIdent = deserialize_endian(ident_raw, EIData::LSB)?;
 
         let eidentview: EIdentView = eident.into();
 
         if matches!(eidentview.class, EIClass::Bit32) {
-            Self::load_32_from_mmap(mmap)
+            Self::load_32(data, opts)
         } else if matches!(eidentview.class, EIClass::Bit64) {
-            Self::load_64_from_mmap(mmap)
+            Self::load_64(data, opts)
         } else {
-            Err(Box::new(std::io::Error::new(
-                ErrorKind::Other,
-                format!("Unknown Elf class {:?}", eidentview),
+            Err(ElfError::Malformed(format!(
+                "Unknown Elf class {:?}",
+                eidentview
             )))
         }
     }
 
+    #[cfg(feature = "std")]
     pub fn load_64_from_mmap(mmap: Mmap) -> Result<Self, Box<dyn Error>> {
-        let config = bincode_options!();
-        let ehdr: E64Hdr = config.deserialize(&mmap[..size_of::<E64Hdr>()])?;
+        Ok(Self::load_64(Backing::Mmap(mmap), &LoadOptions::default())?)
+    }
+
+    fn load_64(data: Backing, opts: &LoadOptions) -> Result<Self, ElfError> {
+        Self::load_class::<Class64>(data, opts)
+    }
+
+    fn load_class<C: ElfClass>(
+        data: Backing,
+        opts: &LoadOptions,
+    ) -> Result<Self, ElfError> {
+        let mmap: &[u8] = &data;
+        let hdr_sz = size_of::<C::Hdr>();
+
+        let hdr_raw = table_entry("ELF header", mmap, 0, 0, hdr_sz)?;
+
+        /* Bytes only, it's the same in either byte order */
+        let ident: EIdent =
+            deserialize_endian(&hdr_raw[..size_of::<EIdent>()], EIData::LSB)?;
+        let endian = EIdentView::from(ident).data;
+
+        let ehdr: C::Hdr = deserialize_endian(hdr_raw, endian)?;
         let ehdr: EHdrView = ehdr.into();
 
         let shstrtab: StrTab;
 
         let shoff = ehdr.section_hdr_offset().0 as usize;
+        let (shnum, phnum) =
+            (*ehdr.section_hdr_ent_num(), *ehdr.prog_hdr_tab_ent_num());
+
+        /* Extended numbering, the counts which don't fit the header are in
+         * the section 0: `sh_size` for `e_shnum` 0, `sh_info` for `e_phnum`
+         * PN_XNUM (`e_shstrndx` of XIndex in `sh_link` below) */
+        let shdr0: Option<E64Shdr> = (shoff > 0
+            && (shnum == 0 || phnum == PN_XNUM))
+            .then(|| {
+                let entry_size = *ehdr.section_hdr_ent_sz() as usize;
+                let raw = table_entry("sections", mmap, shoff, 0, entry_size);
+
+                deserialize_endian::<C::Shdr>(raw.ok()?, endian).ok()
+            })
+            .flatten()
+            .map(Into::into);
 
         let shentries = if shoff > 0 {
             let entry_size = *ehdr.section_hdr_ent_sz() as usize;
-            let entry_num = *ehdr.section_hdr_ent_num() as usize;
+            let entry_num = match shnum {
+                0 => shdr0.as_ref().map_or(0, |sh| {
+                    usize::try_from(sh.size()).unwrap_or(usize::MAX)
+                }),
+                n => n as usize,
+            };
+
+            if let Some(limit) = opts.max_sections {
+                check_limit("sections", entry_num, limit)?;
+            }
+            check_table_fit("sections", mmap, shoff, entry_num, entry_size)?;
 
-            let mut sh_entries = Vec::with_capacity(entry_num);
+            let mut sh_entries: Vec<E64Shdr> = Vec::with_capacity(entry_num);
             for i in 0..entry_num {
-                let sh_entry: E64Shdr = config.deserialize(
-                    &mmap
-                        [shoff + i * entry_size..shoff + (i + 1) * entry_size],
-                )?;
+                let raw = table_entry("sections", mmap, shoff, i, entry_size)?;
+                let sh_entry: C::Shdr = deserialize_endian(raw, endian)?;
 
-                sh_entries.push(sh_entry);
+                sh_entries.push(sh_entry.into());
             }
 
-            let shstr_tab_entry = if *ehdr.section_str_tab_idx() == SID::XIndex
-            {
-                &sh_entries[sh_entries[0].link() as usize]
-            } else {
-                &sh_entries[Into::<usize>::into(*ehdr.section_str_tab_idx())]
+            let shstrtab_idx = match *ehdr.section_str_tab_idx() {
+                SID::XIndex => sh_entries.first().map(|sh| sh.link() as usize),
+                sid => Some(sid.into()),
             };
 
-            let sec_offset = shstr_tab_entry.offset() as usize;
-            let sec_size = shstr_tab_entry.size() as usize;
-
-            shstrtab = StrTab::new(Vec::from_iter(
-                mmap[sec_offset..sec_offset + sec_size].iter().cloned(),
-            ));
+            /* A missing one leaves the section names empty */
+            shstrtab = shstrtab_idx
+                .and_then(|idx| sh_entries.get(idx))
+                .and_then(|sh| file_range(mmap, sh.offset(), sh.size()))
+                .map_or_else(StrTab::empty, |raw| StrTab::new(raw.to_vec()));
 
             let mut sh_view_entries = vec![];
             for entry in sh_entries.iter() {
                 let ty = SHType::from(entry.ty());
-                let flags = SHFLAGS::from(entry.flags() as u32);
-                let name = shstrtab.get(entry.name() as usize).unwrap();
+                let flags = SHFLAGS::from_osabi(
+                    entry.flags(),
+                    ehdr.ident().osabi().into(),
+                );
+                let name =
+                    shstrtab.get(entry.name() as usize).unwrap_or_default();
 
                 let sh_entry_view = SHdrView {
                     name,
+                    name_idx: entry.name(),
                     ty,
                     flags,
                     addr: Hex64(entry.addr()),
@@ -122,169 +488,5111 @@ impl Elf {
                 sh_view_entries.push(sh_entry_view)
             }
 
-            SHEntries(sh_view_entries)
+            SHEntries::new(sh_view_entries)
         } else {
             shstrtab = StrTab::empty();
 
-            SHEntries(vec![])
+            SHEntries::new(vec![])
+        };
+
+
+        let phoff = ehdr.prog_hdr_offset().0 as usize;
+
+        let phentries = if phoff > 0 {
+            let entry_size = *ehdr.prog_hdr_tab_ent_sz() as usize;
+            let entry_num = match (phnum, &shdr0) {
+                (PN_XNUM, Some(sh)) => sh.info() as usize,
+                (n, _) => n as usize,
+            };
+
+            check_table_fit("segments", mmap, phoff, entry_num, entry_size)?;
+
+            let mut ph_entries = Vec::with_capacity(entry_num);
+            for i in 0..entry_num {
+                let raw = table_entry("segments", mmap, phoff, i, entry_size)?;
+                let ph_entry: C::Phdr = deserialize_endian(raw, endian)?;
+
+                ph_entries.push(ph_entry.into());
+            }
+
+            ph_entries
+        } else {
+            vec![]
         };
 
 
-        /* Load strtab */
-        let strtab = load_strtab_from_sh(&shentries, ".strtab", &mmap);
+        /* Load strtab, the one linked from symtab */
+        let strtab = load_linked_strtab(&shentries, SHType::SYMtab, mmap)
+            .unwrap_or_else(StrTab::empty);
 
         /* Load symtab */
-        let symtab =
-            load_sym64tab_from_sh(&shentries, ".symtab", &strtab, ehdr.ty(), &mmap)?;
+        let symtab = load_symtab_from_sh::<C::Sym>(
+            &shentries,
+            SHType::SYMtab,
+            &strtab,
+            ehdr.ty(),
+            mmap,
+            endian,
+            opts,
+        )?;
 
-        /* Load dynstr */
-        let dynstr = load_strtab_from_sh(&shentries, ".dynstr", &mmap);
+        /* Load dynstr, the one linked from dynsym (or dynamic) */
+        let dynstr = load_linked_strtab(&shentries, SHType::DYNSYM, mmap)
+            .or_else(|| load_linked_strtab(&shentries, SHType::DYNAMIC, mmap))
+            .unwrap_or_else(StrTab::empty);
 
         /* Load dynsym */
-        let dynsym =
-            load_sym64tab_from_sh(&shentries, ".dynsym", &&dynstr, ehdr.ty(), &mmap)?;
-
-        #[allow(unused)]
-        if let Some(sh) = shentries.get(".bss") {
-            let sec_offset = sh.offset().0 as usize;
-            let sec_size = *sh.size() as usize;
+        let dynsym = load_symtab_from_sh::<C::Sym>(
+            &shentries,
+            SHType::DYNSYM,
+            &dynstr,
+            ehdr.ty(),
+            mmap,
+            endian,
+            opts,
+        )?;
 
-            let raw = Vec::from_iter(
-                mmap[sec_offset..sec_offset + sec_size].iter().cloned(),
-            );
+        /* Load dynamic */
+        let dynamic =
+            load_dyn_from_sh::<C::Dyn>(&shentries, &dynstr, mmap, endian);
 
-            // println!(".bss raw data {:?}", raw)
-        }
+        /* Load symbol versioning */
+        let versym = load_versym_from_sh(&shentries, mmap, endian);
+        let verdef = load_verdef_from_sh(&shentries, &dynstr, mmap, endian);
+        let verneed = load_verneed_from_sh(&shentries, &dynstr, mmap, endian);
+        let sysv_hash = load_sysv_hash_from_sh(&shentries, mmap, endian);
+        let gnu_hash = load_gnu_hash_from_sh(
+            &shentries,
+            mmap,
+            endian,
+            ehdr.ident().class(),
+        );
 
+        /* Load relocations */
+        let relocations =
+            load_rela_from_sh::<C::Rela>(&shentries, mmap, endian);
 
-        Ok(Self {
+        let mut elf = Self {
             ehdr,
+            phentries,
             shstrtab,
             shentries,
             strtab,
             symtab,
-            dynsym
-        })
+            dynstr,
+            dynsym,
+            dynamic,
+            versym,
+            verdef,
+            verneed,
+            gnu_hash,
+            sysv_hash,
+            relocations,
+            data,
+        };
+
+        /* Attach the version names to the dynamic symbols */
+        for i in 0..elf.dynsym.0.len() {
+            elf.dynsym.0[i].version =
+                elf.dynsym_version(i).map(|ver| ver.name);
+        }
+
+        Ok(elf)
     }
 
 
-    pub fn load_32_from_mmap(_mmap: Mmap) -> Result<Self, Box<dyn Error>> {
-        todo!()
+    #[cfg(feature = "std")]
+    pub fn load_32_from_mmap(mmap: Mmap) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::load_32(Backing::Mmap(mmap), &LoadOptions::default())?)
     }
-}
 
+    /// The fields of a 32-bit file are widened into the same views
+    /// as the 64-bit one
+    fn load_32(data: Backing, opts: &LoadOptions) -> Result<Self, ElfError> {
+        Self::load_class::<Class32>(data, opts)
+    }
 
+    /// Append `data` after the ELF image as an overlay, without touching
+    /// any header, and return the file offset where it begins.
+    pub fn append_overlay(&mut self, data: &[u8]) -> u64 {
+        let buf = self.data.to_mut();
+        let offset = buf.len();
 
-////////////////////////////////////////////////////////////////////////////////
-//// Into Implementations
+        buf.extend_from_slice(data);
 
-impl Into<EIdentView> for EIdent {
-    fn into(self) -> EIdentView {
-        let magic_nums = MagicNums(self.magic_nums());
-        let class: EIClass = unsafe { std::mem::transmute(self.class) };
-        let data: EIData = unsafe { std::mem::transmute(self.data) };
+        offset as u64
+    }
 
-        EIdentView {
-            magic_nums,
-            class,
-            data,
-            version: self.version,
-            osabi: self.osabi,
-            abiversion: self.abiversion,
-            nident: self.nident,
+    /// Bytes after the end of the ELF image, those aren't referenced by
+    /// any header (signature, watermark, self-extracting payload etc.)
+    pub fn overlay(&self) -> &[u8] {
+        let end = self.image_end().min(self.data.len());
+
+        &self.data[end..]
+    }
+
+    /// Serialize the ELF image (including the overlay) back to bytes.
+    ///
+    /// The edits written back are:
+    ///
+    /// - the entry point (`set_entry`)
+    /// - the section headers of `rename_section`, `remove_section`
+    ///   (and `fix_cross_references`), the relocated `.shstrtab` included
+    /// - the `st_shndx` of the `symtab` and `dynsym` symbols
+    /// - the overlay of `append_overlay`
+    ///
+    /// The rest of the image is kept as it was loaded, an unmodified `Elf`
+    /// gives the same bytes as the file. The section contents aren't moved,
+    /// so the offsets aren't recomputed and only `.shstrtab` is rebuilt
+    /// (by `rename_section`), a full re-layout is what `strip` does.
+    ///
+    /// It fails if a widened value doesn't fit back into a 32 bit field, or
+    /// the section header table runs past the image.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, ElfError> {
+        let mut buf = self.data.to_vec();
+
+        let shoff = self.ehdr.section_hdr_offset().0 as usize;
+        let shent_sz = *self.ehdr.section_hdr_ent_sz() as usize;
+        let endian = self.ehdr.ident().data();
+        let is_32 = matches!(self.ehdr.ident().class(), EIClass::Bit32);
+
+        /* e_entry */
+        if is_32 {
+            put_u32(
+                &mut buf,
+                24,
+                narrow("e_entry", self.ehdr.entry.0)?,
+                endian,
+            );
+        } else {
+            put_u64(&mut buf, 24, self.ehdr.entry.0, endian);
+        }
+
+        if shoff == 0 {
+            return Ok(buf);
+        }
+
+        let shnum = self.shentries.0.len();
+
+        if shoff.saturating_add(shnum.saturating_mul(shent_sz)) > buf.len() {
+            return Err(ElfError::Truncated {
+                what: "section header table",
+                offset: shoff,
+                size: shnum.saturating_mul(shent_sz),
+                file_size: buf.len(),
+            });
+        }
+
+        /* e_shoff */
+        if is_32 {
+            put_u32(&mut buf, 32, narrow("e_shoff", shoff as u64)?, endian);
+        } else {
+            put_u64(&mut buf, 40, shoff as u64, endian);
+        }
+
+        for (i, sh) in self.shentries.0.iter().enumerate() {
+            let base = shoff + i * shent_sz;
+            let flags = sh.flags.bits();
+
+            put_u32(&mut buf, base, sh.name_idx, endian);
+            put_u32(&mut buf, base + 4, sh.ty.into(), endian);
+
+            if is_32 {
+                /* Truncated back, they were widened from 32 bit */
+                let fields = [
+                    (8, narrow("sh_flags", flags)?),
+                    (12, narrow("sh_addr", sh.addr.0)?),
+                    (16, narrow("sh_offset", sh.offset.0)?),
+                    (20, narrow("sh_size", sh.size)?),
+                    (24, sh.link),
+                    (28, sh.info),
+                    (32, narrow("sh_addralign", sh.addr_align)?),
+                    (36, narrow("sh_entsize", sh.ent_size)?),
+                ];
+
+                for (off, val) in fields {
+                    put_u32(&mut buf, base + off, val, endian);
+                }
+            } else {
+                put_u64(&mut buf, base + 8, flags, endian);
+                put_u64(&mut buf, base + 16, sh.addr.0, endian);
+                put_u64(&mut buf, base + 24, sh.offset.0, endian);
+                put_u64(&mut buf, base + 32, sh.size, endian);
+                put_u32(&mut buf, base + 40, sh.link, endian);
+                put_u32(&mut buf, base + 44, sh.info, endian);
+                put_u64(&mut buf, base + 48, sh.addr_align, endian);
+                put_u64(&mut buf, base + 56, sh.ent_size, endian);
+            }
+        }
+
+        /* e_shnum, e_shstrndx */
+        let shnum_off = if is_32 { 48 } else { 60 };
+
+        put_u16(&mut buf, shnum_off, self.ehdr.section_hdr_ent_num, endian);
+        put_u16(
+            &mut buf,
+            shnum_off + 2,
+            usize::from(self.ehdr.section_str_tab_idx) as u16,
+            endian,
+        );
+
+        /* st_shndx of the symbols, remapped by `fix_cross_references` */
+        let (sym_sz, shndx_off) = if is_32 { (16, 14) } else { (24, 6) };
+
+        for (ty, symtab) in [
+            (SHType::SYMtab, &self.symtab),
+            (SHType::DYNSYM, &self.dynsym),
+        ] {
+            let Some(sh) = self.shentries.first_by_type(ty) else {
+                continue;
+            };
+            let num = sh.size as usize / sym_sz;
+
+            for (i, sym) in symtab.0.iter().take(num).enumerate() {
+                let at = (sh.offset.0 as usize)
+                    .saturating_add(i * sym_sz + shndx_off);
+
+                put_u16(&mut buf, at, usize::from(sym.shndx) as u16, endian);
+            }
         }
+
+        Ok(buf)
     }
-}
 
-fn load_strtab_from_sh(
-    shentries: &SHEntries,
-    secname: &str,
-    mmap: &Mmap,
-) -> StrTab {
-    if let Some(sh) = shentries.get(secname) {
-        let sec_offset = sh.offset().0 as usize;
-        let sec_size = *sh.size() as usize;
-
-        StrTab::new(Vec::from_iter(
-            mmap[sec_offset..sec_offset + sec_size].iter().cloned(),
-        ))
-    } else {
-        StrTab::empty()
+    /// Move the entry point, it's written back by `to_bytes`
+    pub fn set_entry(&mut self, entry: u64) {
+        self.ehdr.entry = Hex64(entry);
     }
-}
 
-fn load_sym64tab_from_sh(
-    shentries: &SHEntries,
-    secname: &str,
-    strtab: &StrTab,
-    ety: &EType,
-    mmap: &Mmap,
-) -> Result<SymTab, Box<dyn Error>> {
-    let config = bincode_options!();
+    /// Remove the header of a section and fix the references to
+    /// the shifted sections (see [`Elf::fix_cross_references`]).
+    ///
+    /// The section data is left in place as unreferenced bytes,
+    /// the views loaded from it (e.g. `symtab`) are kept until reloaded.
+    ///
+    /// The null section 0 and `.shstrtab` can't be removed.
+    pub fn remove_section(&mut self, name: &str) -> Result<(), ElfError> {
+        let idx = self
+            .shentries
+            .index_of(name)
+            .ok_or_else(|| ElfError::SectionNotFound(name.to_owned()))?;
 
-    Ok(if let Some(sh) = shentries.get(secname) {
-        let sym_sz = size_of::<E64Sym>();
-        debug_assert_eq!(sym_sz, sh.ent_size as usize);
-        let sym_num = sh.size as usize / sym_sz;
+        if idx == 0 || Some(idx) == self.shstrtab_index() {
+            return Err(ElfError::SectionRequired(name.to_owned()));
+        }
 
-        let sec_off = sh.offset.0 as usize;
-        let mut symentries = Vec::with_capacity(sym_num);
+        self.remove_section_at(idx);
 
-        for i in 0..sym_num {
-            let sym: E64Sym = config.deserialize(
-                &mmap[sec_off + i * sym_sz..sec_off + (i + 1) * sym_sz],
-            )?;
+        Ok(())
+    }
 
-            let name = strtab.get(sym.name() as usize).unwrap_or_default();
+    fn remove_section_at(&mut self, idx: usize) {
+        let mapping: Vec<Option<usize>> = (0..self.shentries.0.len())
+            .map(|i| match i.cmp(&idx) {
+                Ordering::Less => Some(i),
+                Ordering::Equal => None,
+                Ordering::Greater => Some(i - 1),
+            })
+            .collect();
 
-            let bind = SymBinding::load_from_info(sym.info());
-            let ty = SymType::load_from_info(sym.info());
-            let visi = SymVisi::load_from_other(sym.other());
-            let shndx = SID::from(sym.shndx());
+        self.shentries.0.remove(idx);
+        self.shentries.reindex();
+        match self.ehdr.section_hdr_ent_num {
+            /* Extended numbering, the count is in the section 0 */
+            0 => self.shentries.0[0].size -= 1,
+            _ => self.ehdr.section_hdr_ent_num -= 1,
+        }
+        self.fix_cross_references(&mapping);
+    }
 
-            let value = match ety {
-                EType::REL => {
-                    if shndx == SID::Common {
-                        SymValue::Alignment(sym.value())
-                    } else {
-                        SymValue::SectionOffset(sym.value())
-                    }
+    /// A stripped copy of the file, with the sections of `opts` dropped.
+    ///
+    /// The image covered by the ELF header, the program headers and the
+    /// segments is kept as it is, so the allocated sections don't move and
+    /// the result still runs. The other kept sections are packed after it
+    /// with a rebuilt `.shstrtab`, and then the section header table.
+    pub fn strip(&self, opts: &StripOptions) -> Result<Vec<u8>, ElfError> {
+        let sections = &self.shentries.0;
+        let shstr_idx = self.shstrtab_index();
+
+        let mut dropped: Vec<bool> = sections
+            .iter()
+            .enumerate()
+            .map(|(i, sh)| {
+                let name = sh.name.as_str();
+
+                i != 0
+                    && Some(i) != shstr_idx
+                    && !sh.flags.has(SHFlagBit::Alloc)
+                    && (opts.symbols
+                        && (matches!(sh.ty, SHType::SYMtab)
+                            || name == ".strtab")
+                        || opts.debug
+                            && (name.starts_with(".debug_")
+                                || name.starts_with(".zdebug_"))
+                        || opts.comment && name == ".comment")
+            })
+            .collect();
+
+        /* The relocations of a dropped section go with it */
+        for (i, sh) in sections.iter().enumerate() {
+            if matches!(sh.ty, SHType::REL | SHType::RELA)
+                && dropped.get(sh.info as usize) == Some(&true)
+                && sh.info != 0
+            {
+                dropped[i] = true;
+            }
+        }
+
+        /* Keep the ones still referred to, until nothing changes */
+        loop {
+            let mut changed = false;
+
+            for (i, sh) in sections.iter().enumerate() {
+                if dropped[i] {
+                    continue;
                 }
-                EType::EXEC | EType::DYN => {
-                    SymValue::VirAddr(Hex64(sym.value()))
+
+                let info = sh.info_is_section_index().then_some(sh.info);
+                for idx in [Some(sh.link), info].into_iter().flatten() {
+                    if let Some(d) = dropped.get_mut(idx as usize) {
+                        changed |= core::mem::take(d);
+                    }
                 }
-                _ => unreachable!("ty: {:#?}", ety),
-            };
+            }
 
-            let symview = SymView {
-                name,
-                bind,
-                ty,
-                visi,
-                shndx,
-                value,
-                size: sym.size(),
+            if !changed {
+                break;
+            }
+        }
+
+        let mut elf = self.clone();
+        for idx in (0..sections.len()).rev().filter(|&i| dropped[i]) {
+            elf.remove_section_at(idx);
+        }
+
+        let ehdr = &self.ehdr;
+        let is_32 = matches!(ehdr.ident().class(), EIClass::Bit32);
+        let word = if is_32 { 4 } else { 8 };
+
+        let mut head = (*ehdr.elf_hdr_sz() as u64).max(
+            ehdr.prog_hdr_offset().0.saturating_add(
+                *ehdr.prog_hdr_tab_ent_sz() as u64
+                    * self.phentries.len() as u64,
+            ),
+        );
+        for ph in self.phentries.iter() {
+            head = head.max(ph.offset.saturating_add(ph.filesz));
+        }
+
+        let file_size = self.data.len();
+        let head = usize::try_from(head)
+            .ok()
+            .filter(|&head| head <= file_size)
+            .ok_or(ElfError::Truncated {
+                what: "segments",
+                offset: 0,
+                size: head as usize,
+                file_size,
+            })?;
+        let mut buf = self.data[..head].to_vec();
+
+        let mut shstrtab = StrTabBuilder::new();
+        for sh in elf.shentries.0.iter_mut().skip(1) {
+            sh.name_idx = shstrtab.intern(&sh.name);
+        }
+        let shstrtab = shstrtab.build();
+        let new_shstr_idx = elf.shstrtab_index();
+
+        for (i, sh) in elf.shentries.0.iter_mut().enumerate().skip(1) {
+            let start = sh.offset.0 as usize;
+            let end = start.saturating_add(sh.size as usize);
+            let is_shstrtab = Some(i) == new_shstr_idx;
+
+            if matches!(sh.ty, SHType::NOBITS) || end <= head && !is_shstrtab {
+                continue;
+            }
+
+            let raw = if is_shstrtab {
+                shstrtab.as_bytes()
+            } else {
+                self.data.get(start..end).ok_or(ElfError::Truncated {
+                    what: "section data",
+                    offset: start,
+                    size: sh.size as usize,
+                    file_size,
+                })?
             };
 
-            symentries.push(symview);
+            pad_to(&mut buf, sh.addr_align);
+            sh.offset = Hex64(buf.len() as u64);
+            sh.size = raw.len() as u64;
+            buf.extend_from_slice(raw);
         }
 
-        SymTab(symentries)
-    } else {
-        SymTab(vec![])
-    })
+        pad_to(&mut buf, word);
+        elf.ehdr.section_hdr_offset = Hex64(buf.len() as u64);
+        buf.resize(
+            buf.len()
+                + *ehdr.section_hdr_ent_sz() as usize * elf.shentries.0.len(),
+            0,
+        );
+        elf.data = Backing::Owned(buf);
 
-}
+        elf.to_bytes()
+    }
 
-impl Debug for Elf {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("Elf")
-            .field("ehdr", &self.ehdr)
-            // .field("shstrtab", &self.shstrtab)
-            .field("shentries", &self.shentries)
-            // .field("strtab", &self.strtab)
-            .field("symtab", &self.symtab)
-            .field("dynsym", &self.dynsym)
-            .finish()
+    /// Rewrite the section references after an edit which moved or removed
+    /// sections, `mapping[old]` is the new index (`None` if removed).
+    ///
+    /// It covers `link` and `info` (where it's a section index) of every
+    /// section header, `e_shstrndx` and the `st_shndx` of the symbols in
+    /// `symtab` and `dynsym`. A reference to a removed section (or out of
+    /// the mapping) becomes `SHN_UNDEF`, so does the symbol of it.
+    pub fn fix_cross_references(&mut self, mapping: &[Option<usize>]) {
+        let remap = |idx: u32| {
+            mapping
+                .get(idx as usize)
+                .copied()
+                .flatten()
+                .map_or(0, |idx| idx as u32)
+        };
+
+        for sh in self.shentries.0.iter_mut() {
+            sh.link = remap(sh.link);
+
+            if sh.info_is_section_index() {
+                sh.info = remap(sh.info);
+            }
+        }
+
+        if let SID::Normal(idx) = self.ehdr.section_str_tab_idx {
+            self.ehdr.section_str_tab_idx =
+                SID::from(remap(idx as u32) as u16);
+        }
+
+        for sym in self.symtab.0.iter_mut().chain(self.dynsym.0.iter_mut()) {
+            sym.strtab_idx = remap(sym.strtab_idx);
+
+            if let SID::Normal(idx) = sym.shndx {
+                sym.shndx = SID::from(remap(idx as u32) as u16);
+            }
+        }
+    }
+
+    /// Give a section a new name.
+    ///
+    /// The name is interned into the section header string table,
+    /// if it has to grow, the table is relocated to the end of the image
+    /// (before the overlay), the other sections keep their offsets.
+    pub fn rename_section(
+        &mut self,
+        old: &str,
+        new: &str,
+    ) -> Result<(), ElfError> {
+        let idx = self
+            .shentries
+            .index_of(old)
+            .ok_or_else(|| ElfError::SectionNotFound(old.to_owned()))?;
+
+        if old == new {
+            return Ok(());
+        }
+
+        let shstr_idx = self.shstrtab_index().ok_or_else(|| {
+            ElfError::SectionNotFound(".shstrtab".to_owned())
+        })?;
+
+        let mut builder = StrTabBuilder::from(&self.shstrtab);
+        let old_len = builder.len();
+        let name_idx = builder.intern(new);
+
+        if builder.len() != old_len {
+            let table = builder.build();
+
+            let end = self.image_end().min(self.data.len());
+            self.data
+                .to_mut()
+                .splice(end..end, table.as_bytes().iter().cloned());
+
+            let shstr_sh = &mut self.shentries.0[shstr_idx];
+            shstr_sh.offset = Hex64(end as u64);
+            shstr_sh.size = table.as_bytes().len() as u64;
+
+            self.shstrtab = table;
+        }
+
+        let sh = &mut self.shentries.0[idx];
+        sh.name = new.to_owned();
+        sh.name_idx = name_idx;
+        self.shentries.reindex();
+
+        Ok(())
+    }
+
+    /// Index of the section header string table section
+    pub(crate) fn shstrtab_index(&self) -> Option<usize> {
+        let idx = match *self.ehdr.section_str_tab_idx() {
+            SID::XIndex => self.shentries.0.first()?.link as usize,
+            sid => sid.into(),
+        };
+
+        (idx < self.shentries.0.len()).then_some(idx)
+    }
+
+    /// Symbol which a relocation of the RELA section `section` targets,
+    /// from the symbol table the section `link` refers to.
+    ///
+    /// `None` for the relocations without a symbol (index 0),
+    /// e.g. `R_X86_64_RELATIVE`.
+    pub fn relocation_symbol(
+        &self,
+        section: &str,
+        rela: &RelaView,
+    ) -> Option<&SymView> {
+        let sh = self.shentries.get(section)?;
+
+        let symtab = match self.shentries.0.get(sh.link as usize)?.ty {
+            SHType::SYMtab => &self.symtab,
+            SHType::DYNSYM => &self.dynsym,
+            _ => return None,
+        };
+
+        match rela.sym {
+            0 => None,
+            idx => symtab.0.get(idx as usize),
+        }
+    }
+
+    /// PLT stubs paired with the function each one resolves to, by
+    /// the `R_X86_64_JUMP_SLOT` relocations of `.rela.plt` (x86-64 only).
+    ///
+    /// The stubs are 16 bytes each in the relocation order: of `.plt.sec`
+    /// for the IBT-enabled layout, else of `.plt` after the PLT0 header.
+    pub fn plt_entries(&self) -> Vec<PltEntry> {
+        let machine = self.ehdr.machine();
+
+        if !matches!(machine, EMachine::X86_64) {
+            return vec![];
+        }
+
+        let Some(entry_size) = machine.plt_entry_size() else {
+            return vec![];
+        };
+
+        let Some((_, relas)) = self
+            .relocations
+            .iter()
+            .find(|(name, _)| name == ".rela.plt")
+        else {
+            return vec![];
+        };
+
+        let first_stub = if let Some(sec) = self.shentries.get(".plt.sec") {
+            sec.addr.0
+        } else if let Some(plt) = self.shentries.get(".plt") {
+            plt.addr.0 + entry_size
+        } else {
+            return vec![];
+        };
+
+        relas
+            .iter()
+            .enumerate()
+            .filter(|(_, rela)| {
+                RelocX86_64::from(rela.ty) == RelocX86_64::JUMPSLOT
+            })
+            .map(|(index, rela)| PltEntry {
+                index,
+                addr: Hex64(first_stub + index as u64 * entry_size),
+                got: rela.offset,
+                symbol: self
+                    .relocation_symbol(".rela.plt", rela)
+                    .map(|sym| sym.name.clone())
+                    .unwrap_or_default(),
+            })
+            .collect()
+    }
+
+    /// Symbols defined in the section, from `.symtab`
+    /// (or `.dynsym` for the stripped file).
+    pub fn symbols_in_section(&self, name: &str) -> Vec<&SymView> {
+        let Some(idx) = self.shentries.index_of(name) else {
+            return vec![];
+        };
+
+        let symtab = if self.symtab.0.is_empty() {
+            &self.dynsym
+        } else {
+            &self.symtab
+        };
+
+        symtab
+            .0
+            .iter()
+            .filter(|sym| sym.shndx == SID::Normal(idx as u16))
+            .collect()
+    }
+
+    /// Symbol of a relocatable object covering `offset` of the section,
+    /// i.e. `offset` is in `[value, value + size)`.
+    ///
+    /// `None` for the other file types, where the symbol values are
+    /// addresses rather than section offsets.
+    pub fn symbol_at_section_offset(
+        &self,
+        section: &str,
+        offset: u64,
+    ) -> Option<&SymView> {
+        if !matches!(self.ehdr.ty(), EType::REL) {
+            return None;
+        }
+
+        self.symbols_in_section(section).into_iter().find(|sym| {
+            let SymValue::SectionOffset(value) = sym.value else {
+                return false;
+            };
+
+            (value..value.saturating_add(sym.size)).contains(&offset)
+        })
+    }
+
+    /// Symbol named `name`, a defined one of `.symtab` or `.dynsym` first,
+    /// otherwise an undefined one
+    pub fn symbol(&self, name: &str) -> Option<&SymView> {
+        let syms = self.symbols_by_name(name);
+
+        syms.iter()
+            .find(|sym| !matches!(sym.shndx, SID::Undef))
+            .or(syms.first())
+            .copied()
+    }
+
+    /// Defined dynamic symbol named `name`, looked up by the `.gnu.hash`
+    /// table, else the `.hash` one, or by a linear scan without either
+    pub fn dynamic_symbol_lookup(&self, name: &str) -> Option<&SymView> {
+        match (&self.gnu_hash, &self.sysv_hash) {
+            (Some(table), _) => table
+                .lookup(name, &self.dynsym)
+                .and_then(|idx| self.dynsym.get(idx)),
+            (None, Some(_)) => self.sysv_hash_lookup(name),
+            (None, None) => self.dynsym.0.iter().find(|sym| {
+                sym.name == name && !matches!(sym.shndx, SID::Undef)
+            }),
+        }
+    }
+
+    /// Defined dynamic symbol named `name` by the SysV `.hash` table,
+    /// `None` without the table
+    pub fn sysv_hash_lookup(&self, name: &str) -> Option<&SymView> {
+        self.sysv_hash
+            .as_ref()?
+            .lookup(name, &self.dynsym)
+            .and_then(|idx| self.dynsym.get(idx))
+    }
+
+    /// All the symbols named `name` (e.g. of both `.symtab` and `.dynsym`,
+    /// or the local duplicates), `.symtab` first
+    pub fn symbols_by_name(&self, name: &str) -> Vec<&SymView> {
+        if name.is_empty() {
+            return vec![];
+        }
+
+        self.symtab
+            .0
+            .iter()
+            .chain(self.dynsym.0.iter())
+            .filter(|sym| sym.name == name)
+            .collect()
+    }
+
+    /// Compiled with the stack protector, i.e. `__stack_chk_fail` is
+    /// referenced (or defined, statically linked)
+    pub fn has_stack_canary(&self) -> bool {
+        self.any_symbol_named(|name| name == "__stack_chk_fail")
+    }
+
+    /// Compiled with `_FORTIFY_SOURCE`, i.e. some of the checked libc
+    /// functions (`__memcpy_chk`, `__printf_chk` and the like) are used
+    pub fn uses_fortify(&self) -> bool {
+        self.any_symbol_named(|name| {
+            name.starts_with("__")
+                && name.ends_with("_chk")
+                && !name.starts_with("__stack_chk")
+        })
+    }
+
+    /// Some symbol of `.symtab` or `.dynsym` has a name accepted by `pred`,
+    /// without the `@VERSION` that the linkers append in `.strtab`
+    fn any_symbol_named(&self, pred: impl Fn(&str) -> bool) -> bool {
+        self.symtab
+            .0
+            .iter()
+            .chain(self.dynsym.0.iter())
+            .map(|sym| self.resolve_sym_name(sym))
+            .any(|name| pred(name.split('@').next().unwrap_or_default()))
+    }
+
+    /// Function or object symbol containing the virtual address `addr` of
+    /// an executable or shared object, i.e. `addr` is in
+    /// `[value, value + size)`.
+    ///
+    /// A zero-size symbol covers the rest of its section, so of the
+    /// overlapping ones the nearest preceding symbol is taken.
+    pub fn symbol_at_addr(&self, addr: u64) -> Option<&SymView> {
+        if !matches!(self.ehdr.ty(), EType::EXEC | EType::DYN) {
+            return None;
+        }
+
+        self.symtab
+            .0
+            .iter()
+            .chain(self.dynsym.0.iter())
+            .filter(|sym| matches!(sym.ty, SymType::Func | SymType::Object))
+            .filter_map(|sym| {
+                let SymValue::VirAddr(Hex64(value)) = sym.value else {
+                    return None;
+                };
+
+                let end = if sym.size > 0 {
+                    value.saturating_add(sym.size)
+                } else {
+                    let SID::Normal(idx) = sym.shndx else {
+                        return None;
+                    };
+                    let sh = self.shentries.0.get(idx as usize)?;
+
+                    sh.addr.0.saturating_add(sh.size)
+                };
+
+                (value..end).contains(&addr).then_some((value, sym))
+            })
+            .max_by_key(|(value, sym)| (*value, sym.size > 0))
+            .map(|(_, sym)| sym)
+    }
+
+    /// Name of `sym` borrowed from the string table, the one to read
+    /// the names by of the lazy mode ([`Elf::parse_lazy`]).
+    ///
+    /// It's read from the string table the symbol was loaded with
+    /// (`strtab_idx`), a clone of the symbol included. An invalid UTF-8
+    /// name is empty.
+    pub fn resolve_sym_name(&self, sym: &SymView) -> &str {
+        self.shentries
+            .0
+            .get(sym.strtab_idx as usize)
+            .and_then(|sh| self.sh_data(sh))
+            .and_then(|raw| {
+                SectionData::new(raw, self.ehdr.ident().data())
+                    .read_cstr(sym.name_idx as usize)
+            })
+            .unwrap_or_default()
+    }
+
+    /// Dynamic symbols paired with their resolved GNU symbol version,
+    /// `None` for local or unversioned (global base) symbols.
+    pub fn versioned_dynsyms(&self) -> Vec<(&SymView, Option<VersionInfo>)> {
+        self.dynsym
+            .0
+            .iter()
+            .enumerate()
+            .map(|(i, sym)| (sym, self.dynsym_version(i)))
+            .collect()
+    }
+
+    fn dynsym_version(&self, idx: usize) -> Option<VersionInfo> {
+        let raw = *self.versym.get(idx)?;
+        let ndx = raw & 0x7fff;
+        let hidden = raw & 0x8000 != 0;
+
+        /* 0: local, 1: global (base definition) */
+        if ndx <= 1 {
+            return None;
+        }
+
+        if let Some(def) = self.verdef.iter().find(|def| def.ndx == ndx) {
+            return Some(VersionInfo {
+                name: def.name.clone(),
+                hidden,
+                is_default: !hidden,
+            });
+        }
+
+        let aux = self
+            .verneed
+            .iter()
+            .flat_map(|need| need.aux.iter())
+            .find(|aux| aux.other == ndx)?;
+
+        Some(VersionInfo {
+            name: aux.name.clone(),
+            hidden,
+            is_default: false,
+        })
+    }
+
+    /// Undefined dynamic symbols grouped by the `DT_NEEDED` library which
+    /// provides them, with the required version.
+    ///
+    /// The library is only recorded (in `.gnu.version_r`) for the versioned
+    /// references, the unversioned ones are under the empty name.
+    pub fn imports_by_library(
+        &self,
+    ) -> HashMap<String, Vec<(String, Option<String>)>> {
+        let mut imports: HashMap<String, Vec<(String, Option<String>)>> = self
+            .needed_libraries()
+            .into_iter()
+            .map(|name| (name, vec![]))
+            .collect();
+
+        for (i, sym) in self.dynsym.0.iter().enumerate() {
+            if !matches!(sym.shndx, SID::Undef) || sym.name.is_empty() {
+                continue;
+            }
+
+            let ndx = self.versym.get(i).map_or(0, |raw| raw & 0x7fff);
+            let need = self.verneed.iter().find_map(|need| {
+                let aux = need.aux.iter().find(|aux| aux.other == ndx)?;
+
+                Some((need.file.clone(), aux.name.clone()))
+            });
+
+            let (file, version) = match need {
+                Some((file, version)) => (file, Some(version)),
+                None => (String::new(), None),
+            };
+
+            imports
+                .entry(file)
+                .or_default()
+                .push((sym.name.clone(), version));
+        }
+
+        imports
+    }
+
+    /// Libraries of the `DT_NEEDED` entries in file order, empty for
+    /// a statically linked or relocatable object
+    pub fn needed_libraries(&self) -> Vec<String> {
+        self.dynamic
+            .iter()
+            .filter(|entry| entry.tag == DynTag::NEEDED)
+            .filter_map(|entry| entry.name.clone())
+            .collect()
+    }
+
+    /// `DT_SONAME` of a shared object
+    pub fn soname(&self) -> Option<String> {
+        self.dynamic
+            .iter()
+            .find(|entry| entry.tag == DynTag::SONAME)
+            .and_then(|entry| entry.name.clone())
+    }
+
+    /// Search paths of `DT_RPATH`
+    ///
+    /// An empty path (e.g. of `a::b`) is kept, it means the current
+    /// working directory for the dynamic linker.
+    pub fn rpath(&self) -> Vec<String> {
+        self.search_paths(DynTag::RPATH)
+    }
+
+    /// Search paths of `DT_RUNPATH`, as [`Elf::rpath`]
+    pub fn runpath(&self) -> Vec<String> {
+        self.search_paths(DynTag::RUNPATH)
+    }
+
+    fn search_paths(&self, tag: DynTag) -> Vec<String> {
+        self.dynamic
+            .iter()
+            .filter(|entry| entry.tag == tag)
+            .filter_map(|entry| entry.name.as_ref())
+            .flat_map(|paths| paths.split(':'))
+            .map(ToOwned::to_owned)
+            .collect()
+    }
+
+    /// Addresses of the constructors in the order the dynamic linker runs
+    /// them at load: each of `DT_PREINIT_ARRAY` (executable only),
+    /// then `DT_INIT`, then each of `DT_INIT_ARRAY`.
+    pub fn constructors(&self) -> Vec<u64> {
+        let mut addrs =
+            self.func_array(DynTag::PREINITARRAY, DynTag::PREINITARRAYSZ);
+
+        addrs.extend(self.dyn_val(DynTag::INIT));
+        addrs.extend(self.func_array(DynTag::INITARRAY, DynTag::INITARRAYSZ));
+
+        addrs
+    }
+
+    /// Addresses of the destructors in the order they run at unload:
+    /// `DT_FINI_ARRAY` in reverse, then `DT_FINI`.
+    pub fn destructors(&self) -> Vec<u64> {
+        let mut addrs =
+            self.func_array(DynTag::FINIARRAY, DynTag::FINIARRAYSZ);
+        addrs.reverse();
+
+        addrs.extend(self.dyn_val(DynTag::FINI));
+
+        addrs
+    }
+
+    fn dyn_val(&self, tag: DynTag) -> Option<u64> {
+        self.dynamic
+            .iter()
+            .find(|entry| entry.tag == tag)
+            .map(|entry| entry.val)
+    }
+
+    /// Function pointers of the array at the address of `tag`,
+    /// with the size in bytes of `size_tag`
+    fn func_array(&self, tag: DynTag, size_tag: DynTag) -> Vec<u64> {
+        let (Some(addr), Some(size)) =
+            (self.dyn_val(tag), self.dyn_val(size_tag))
+        else {
+            return vec![];
+        };
+
+        let Some(raw) = self.data_at_vaddr(addr, size as usize) else {
+            return vec![];
+        };
+
+        self.pointers(raw)
+    }
+
+    /// Entries of `.preinit_array` and `.init_array` (the sections of the
+    /// types, in the section order) as they run, the functions named by
+    /// [`Elf::symbol_at_addr`].
+    ///
+    /// Read from the sections rather than the dynamic section as
+    /// [`Elf::constructors`], so a static executable has them too.
+    pub fn init_functions(&self) -> Vec<u64> {
+        let mut addrs = self.section_func_array(SHType::PREINITARRAY);
+
+        addrs.extend(self.section_func_array(SHType::INITARRAY));
+
+        addrs
+    }
+
+    /// Entries of `.fini_array` as they run, i.e. in reverse
+    pub fn fini_functions(&self) -> Vec<u64> {
+        let mut addrs = self.section_func_array(SHType::FINIARRAY);
+        addrs.reverse();
+
+        addrs
+    }
+
+    /// `init_functions` paired with the names of the functions, `None` of
+    /// an address which no symbol covers (e.g. of a stripped file)
+    pub fn init_functions_named(&self) -> Vec<(u64, Option<&str>)> {
+        self.with_sym_names(self.init_functions())
+    }
+
+    /// `fini_functions` paired with the names as `init_functions_named`
+    pub fn fini_functions_named(&self) -> Vec<(u64, Option<&str>)> {
+        self.with_sym_names(self.fini_functions())
+    }
+
+    /// The names by [`Elf::symbol_at_addr`], they are resolved by
+    /// [`Elf::resolve_sym_name`] so that the lazy mode has them too.
+    fn with_sym_names(&self, addrs: Vec<u64>) -> Vec<(u64, Option<&str>)> {
+        addrs
+            .into_iter()
+            .map(|addr| {
+                let sym = self.symbol_at_addr(addr);
+
+                (addr, sym.map(|sym| self.resolve_sym_name(sym)))
+            })
+            .collect()
+    }
+
+    /// Function pointers of all the sections of the type
+    fn section_func_array(&self, ty: SHType) -> Vec<u64> {
+        self.shentries
+            .iter()
+            .filter(|sh| sh.ty == ty)
+            .filter_map(|sh| self.sh_data(sh))
+            .flat_map(|raw| self.pointers(raw))
+            .collect()
+    }
+
+    /// `raw` as the pointers of the class, in the byte order of the file
+    fn pointers(&self, raw: &[u8]) -> Vec<u64> {
+        let mut raw = SectionData::new(raw, self.ehdr.ident().data());
+
+        if matches!(self.ehdr.ident().class(), EIClass::Bit32) {
+            core::iter::from_fn(|| raw.next_u32().map(u64::from)).collect()
+        } else {
+            core::iter::from_fn(|| raw.next_u64()).collect()
+        }
+    }
+
+    /// Note entries of all the NOTE sections in the section order, or of
+    /// the NOTE segments for a file without sections (e.g. a core dump)
+    pub fn notes(&self) -> Vec<NoteView> {
+        let sections: Vec<&SHdrView> = self
+            .shentries
+            .0
+            .iter()
+            .filter(|sh| matches!(sh.ty, SHType::NOTE))
+            .collect();
+
+        if !sections.is_empty() {
+            return sections
+                .into_iter()
+                .flat_map(|sh| self.notes_of(sh))
+                .collect();
+        }
+
+        self.phentries
+            .iter()
+            .filter(|ph| matches!(ph.ty, PhType::NOTE))
+            .filter_map(|ph| {
+                let raw = file_range(&self.data, ph.offset, ph.filesz)?;
+                let cursor = SectionData::new(raw, self.ehdr.ident().data());
+
+                Some(parse_notes(cursor, ph.align as usize))
+            })
+            .flatten()
+            .collect()
+    }
+
+    /// GNU build ID of `.note.gnu.build-id` in lower hex
+    pub fn build_id(&self) -> Option<String> {
+        let sh = self.shentries.get(".note.gnu.build-id")?;
+
+        self.notes_of(sh)
+            .into_iter()
+            .find(|note| note.name == "GNU" && note.ty == NT_GNU_BUILD_ID)
+            .map(|note| {
+                note.desc.iter().map(|b| format!("{:02x}", b)).collect()
+            })
+    }
+
+    /// Build ID the Go linker records in `.note.go.buildid`
+    pub fn go_build_id(&self) -> Option<String> {
+        let sh = self.shentries.get(".note.go.buildid")?;
+
+        self.notes_of(sh)
+            .into_iter()
+            .find(|note| note.name == "Go" && note.ty == NT_GO_BUILDID)
+            .and_then(|note| String::from_utf8(note.desc).ok())
+    }
+
+    /// Hardware security features of `.note.gnu.property` (or the
+    /// `PT_GNU_PROPERTY` segment): the `FEATURE_1_AND` property of x86
+    /// (CET) or AArch64. `None` without the note.
+    ///
+    /// The properties are `pr_type`, `pr_datasz` and the data, each one
+    /// padded to the 8 bytes of a 64-bit file or the 4 of a 32-bit one.
+    pub fn gnu_properties(&self) -> Option<GnuProperties> {
+        let note = self.notes().into_iter().find(|note| {
+            note.name == "GNU" && note.ty == NT_GNU_PROPERTY_TYPE_0
+        })?;
+
+        let ident = self.ehdr.ident();
+        let align = match ident.class() {
+            EIClass::Bit32 => 4,
+            _ => 8,
+        };
+        let mut raw = SectionData::new(&note.desc, ident.data());
+        let mut props = GnuProperties::default();
+
+        /* A truncated property ends the list */
+        while let (Some(ty), Some(size)) = (raw.next_u32(), raw.next_u32()) {
+            let pos = raw.position();
+            let data = raw.raw().get(pos..).unwrap_or_default();
+            let Some(data) = data.get(..size as usize) else {
+                break;
+            };
+            let features = SectionData::new(data, ident.data()).read_u32(0);
+
+            match (self.ehdr.machine(), ty, features) {
+                (
+                    EMachine::X86_64 | EMachine::_386,
+                    GNU_PROPERTY_X86_FEATURE_1_AND,
+                    Some(bits),
+                ) => {
+                    props.ibt = bits & 0x1 != 0;
+                    props.shadow_stack = bits & 0x2 != 0;
+                }
+                (
+                    EMachine::AARCH64,
+                    GNU_PROPERTY_AARCH64_FEATURE_1_AND,
+                    Some(bits),
+                ) => {
+                    props.bti = bits & 0x1 != 0;
+                    props.pac = bits & 0x2 != 0;
+                }
+                _ => (),
+            }
+
+            if raw.seek(pos + data.len().next_multiple_of(align)).is_none() {
+                break;
+            }
+        }
+
+        Some(props)
+    }
+
+    /// Process and thread state of a core dump, of the `CORE` notes:
+    /// `NT_PRPSINFO` and a `NT_PRSTATUS` per thread.
+    ///
+    /// The notes are read in the layout of 64-bit Linux, the registers
+    /// are decoded only for x86-64 so far. `None` if it isn't a 64-bit
+    /// core dump or there is none of the notes.
+    pub fn core_info(&self) -> Option<CoreInfo> {
+        if !matches!(self.ehdr.ty(), EType::CORE)
+            || !matches!(self.ehdr.ident().class(), EIClass::Bit64)
+        {
+            return None;
+        }
+
+        let endian = self.ehdr.ident().data();
+        let notes = self.notes();
+        let core_notes = || notes.iter().filter(|note| note.name == "CORE");
+
+        let threads: Vec<CoreThread> = core_notes()
+            .filter(|note| note.ty == NT_PRSTATUS)
+            .filter_map(|note| {
+                /* elf_prstatus: pr_cursig at 12, pr_pid at 32,
+                 * pr_reg at 112 */
+                let raw = SectionData::new(&note.desc, endian);
+
+                let registers = match self.ehdr.machine() {
+                    EMachine::X86_64 => X86_64_REGS
+                        .iter()
+                        .enumerate()
+                        .map(|(i, &name)| {
+                            Some((name, raw.read_u64(112 + i * 8)?))
+                        })
+                        .collect::<Option<_>>()?,
+                    _ => vec![],
+                };
+
+                Some(CoreThread {
+                    pid: raw.read_u32(32)?,
+                    signal: raw.read_u16(12)?,
+                    registers,
+                })
+            })
+            .collect();
+
+        /* elf_prpsinfo: pr_pid at 24, pr_fname[16] at 40,
+         * pr_psargs[80] at 56 */
+        let psinfo = core_notes()
+            .find(|note| note.ty == NT_PRPSINFO)
+            .map(|note| SectionData::new(&note.desc, endian));
+        let fixed_str = |off: usize, len: usize| {
+            let raw = psinfo.as_ref()?.read_bytes(off, len)?;
+            let end = raw.iter().position(|&b| b == 0).unwrap_or(len);
+
+            Some(String::from_utf8_lossy(&raw[..end]).into_owned())
+        };
+
+        let pid = psinfo
+            .as_ref()
+            .and_then(|raw| raw.read_u32(24))
+            .or_else(|| threads.first().map(|thread| thread.pid))?;
+
+        Some(CoreInfo {
+            pid,
+            signal: threads.first().map_or(0, |thread| thread.signal),
+            command: fixed_str(40, 16).unwrap_or_default(),
+            /* The kernel turns the NULs between the arguments into spaces,
+             * the trailing one included */
+            args: fixed_str(56, 80)
+                .map(|args| args.trim_end().to_owned())
+                .unwrap_or_default(),
+            threads,
+        })
+    }
+
+    /// Decode `.eh_frame_hdr` (or the `PT_GNU_EH_FRAME` segment of a file
+    /// without sections).
+    ///
+    /// The `absptr`, `pcrel` and `datarel` pointers are resolved, `None`
+    /// for another application or an indirect one, or a version but 1.
+    pub fn eh_frame_hdr(&self) -> Option<EhFrameHdr> {
+        let (addr, raw) = match self.shentries.get(".eh_frame_hdr") {
+            Some(sh) => (sh.addr.0, self.sh_data(sh)?),
+            None => {
+                let ph = self
+                    .phentries
+                    .iter()
+                    .find(|ph| matches!(ph.ty, PhType::GNUEHFRAME))?;
+
+                (ph.vaddr.0, file_range(&self.data, ph.offset, ph.filesz)?)
+            }
+        };
+
+        let is_64 = matches!(self.ehdr.ident().class(), EIClass::Bit64);
+        let mut cursor = SectionData::new(raw, self.ehdr.ident().data());
+
+        let version = cursor.next_u8()?;
+        if version != 1 {
+            return None;
+        }
+
+        let eh_frame_ptr_enc = cursor.next_u8()?;
+        let fde_count_enc = cursor.next_u8()?;
+        let table_enc = cursor.next_u8()?;
+
+        let mut read = |enc| read_eh_pointer(&mut cursor, enc, addr, is_64);
+
+        let eh_frame_ptr = read(eh_frame_ptr_enc)?;
+        let fde_count =
+            if fde_count_enc == DW_EH_PE_OMIT || table_enc == DW_EH_PE_OMIT {
+                0
+            } else {
+                read(fde_count_enc)?
+            };
+
+        /* Up to the end of the section for a bogus count */
+        let table = (0..fde_count)
+            .map_while(|_| {
+                Some((Hex64(read(table_enc)?), Hex64(read(table_enc)?)))
+            })
+            .collect();
+
+        Some(EhFrameHdr {
+            version,
+            eh_frame_ptr_enc,
+            fde_count_enc,
+            table_enc,
+            eh_frame_ptr: Hex64(eh_frame_ptr),
+            table,
+        })
+    }
+
+    /// Parse the note entries of a NOTE section
+    fn notes_of(&self, sh: &SHdrView) -> Vec<NoteView> {
+        let Some(raw) = self.sh_data(sh) else {
+            return vec![];
+        };
+
+        let cursor = SectionData::new(raw, self.ehdr.ident().data());
+
+        parse_notes(cursor, sh.addr_align as usize)
+    }
+
+    /// Translate a runtime virtual address into a file offset through
+    /// the LOAD segments.
+    ///
+    /// It's `None` outside of the file-backed part of any segment,
+    /// e.g. `.bss` or memory that wasn't dumped into a core file
+    /// (`filesz < memsz`).
+    pub fn vaddr_to_offset(&self, vaddr: u64) -> Option<u64> {
+        let ph = self.load_segment_of(vaddr)?;
+
+        ph.offset.checked_add(vaddr - ph.vaddr.0)
+    }
+
+    /// Read `len` bytes at a runtime virtual address, the range shouldn't
+    /// go beyond the file-backed part of the containing LOAD segment.
+    pub fn data_at_vaddr(&self, vaddr: u64, len: usize) -> Option<&[u8]> {
+        let ph = self.load_segment_of(vaddr)?;
+
+        if (vaddr - ph.vaddr.0).checked_add(len as u64)? > ph.filesz {
+            return None;
+        }
+
+        let start =
+            usize::try_from(ph.offset.checked_add(vaddr - ph.vaddr.0)?)
+                .ok()?;
+
+        self.data.get(start..start.checked_add(len)?)
+    }
+
+    /// Path of the program interpreter requested by the INTERP segment,
+    /// `None` for the static executables and the relocatable objects
+    pub fn interpreter(&self) -> Option<String> {
+        let ph = self
+            .phentries
+            .iter()
+            .find(|ph| matches!(ph.ty, PhType::INTERP))?;
+
+        let start = usize::try_from(ph.offset).ok()?;
+        let end = start.checked_add(usize::try_from(ph.filesz).ok()?)?;
+        let raw = self.data.get(start..end)?;
+        let path = raw.split(|&b| b == 0).next().unwrap_or_default();
+
+        Some(String::from_utf8_lossy(path).into_owned())
+    }
+
+    /// The TLS segment and the TLS symbols defined in it, from `.symtab`
+    /// (or `.dynsym` for the stripped file)
+    pub fn tls_info(&self) -> Option<TlsInfo> {
+        let ph = self
+            .phentries
+            .iter()
+            .find(|ph| matches!(ph.ty, PhType::TLS))?;
+
+        let symtab = if self.symtab.0.is_empty() {
+            &self.dynsym
+        } else {
+            &self.symtab
+        };
+
+        Some(TlsInfo {
+            vaddr: ph.vaddr,
+            memsz: ph.memsz,
+            filesz: ph.filesz,
+            align: ph.align,
+            symbols: symtab
+                .0
+                .iter()
+                .filter(|sym| {
+                    matches!(sym.ty, SymType::TLS)
+                        && !matches!(sym.shndx, SID::Undef)
+                })
+                .cloned()
+                .collect(),
+        })
+    }
+
+    /// A `DYN` file is a position-independent executable rather than a
+    /// shared object, if it requests an interpreter or has `DF_1_PIE`
+    pub fn is_pie(&self) -> bool {
+        matches!(self.ehdr.ty(), EType::DYN)
+            && (self.interpreter().is_some() || self.has_pie_flag())
+    }
+
+    /// Kind of the file, `None` for the unknown and reserved `e_type`
+    pub fn file_kind(&self) -> Option<FileKind> {
+        Some(match self.ehdr.ty() {
+            EType::REL => FileKind::Relocatable,
+            EType::EXEC => FileKind::Executable,
+            EType::DYN if self.is_pie() => FileKind::Pie,
+            EType::DYN => FileKind::SharedObject,
+            EType::CORE => FileKind::Core,
+            _ => return None,
+        })
+    }
+
+    /// `DF_1_PIE` is set in the `DT_FLAGS_1` of the dynamic section
+    pub(crate) fn has_pie_flag(&self) -> bool {
+        const DF_1_PIE: u64 = 0x0800_0000;
+
+        self.dynamic.iter().any(|entry| {
+            entry.tag() == DynTag::FLAGS1 && entry.val() & DF_1_PIE != 0
+        })
+    }
+
+    /// RELRO protection, by the `PT_GNU_RELRO` segment and the immediate
+    /// binding (`DT_BIND_NOW`, `DF_BIND_NOW` or `DF_1_NOW`)
+    pub fn relro(&self) -> RelroStatus {
+        const DF_BIND_NOW: u64 = 0x8;
+        const DF_1_NOW: u64 = 0x1;
+
+        if !self
+            .phentries
+            .iter()
+            .any(|ph| matches!(ph.ty, PhType::GNURELRO))
+        {
+            return RelroStatus::None;
+        }
+
+        let bind_now = self.dynamic.iter().any(|entry| match entry.tag() {
+            DynTag::BINDNOW => true,
+            DynTag::FLAGS => entry.val() & DF_BIND_NOW != 0,
+            DynTag::FLAGS1 => entry.val() & DF_1_NOW != 0,
+            _ => false,
+        });
+
+        if bind_now {
+            RelroStatus::Full
+        } else {
+            RelroStatus::Partial
+        }
+    }
+
+    /// The stack isn't executable, by the flags of the `PT_GNU_STACK`
+    /// segment. Without the segment, the kernel makes it executable.
+    pub fn nx_enabled(&self) -> bool {
+        self.phentries
+            .iter()
+            .find(|ph| matches!(ph.ty, PhType::GNUSTACK))
+            .is_some_and(|ph| !ph.flags.has(PFlagBit::X))
+    }
+
+    /// Sections having all the flags of `required`
+    pub fn sections_with_flags(
+        &self,
+        required: &[SHFlagBit],
+    ) -> Vec<&SHdrView> {
+        self.shentries
+            .iter()
+            .filter(|sh| required.iter().all(|&flag| sh.has_flag(flag)))
+            .collect()
+    }
+
+    /// Sections both writable and executable, a W^X violation
+    pub fn writable_executable_sections(&self) -> Vec<&SHdrView> {
+        self.sections_with_flags(&[SHFlagBit::Write, SHFlagBit::ExecInstr])
+    }
+
+    /// SHA-256 of the code, stable across the rebuilds differing only in
+    /// the timestamps, the build ID, the comments or the debug info.
+    ///
+    /// Hashed are the sections both `SHF_ALLOC` and `SHF_EXECINSTR` having
+    /// file content (e.g. `.init`, `.plt`, `.plt.sec`, `.text`, `.fini`),
+    /// in the section header table order. Each one is fed as its name, a
+    /// NUL, its size as a little-endian `u64`, then its bytes. No other
+    /// section (the headers neither) takes part.
+    pub fn code_fingerprint(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+
+        for sh in
+            self.sections_with_flags(&[SHFlagBit::Alloc, SHFlagBit::ExecInstr])
+        {
+            let Some(data) = self.sh_data(sh) else {
+                continue;
+            };
+
+            hasher.update(sh.name.as_bytes());
+            hasher.update([0]);
+            hasher.update((data.len() as u64).to_le_bytes());
+            hasher.update(data);
+        }
+
+        hasher.finalize().into()
+    }
+
+    /// Sections lying in the segment of the index, as the "Section to
+    /// Segment mapping" of `readelf -l` (the null section never does)
+    pub fn sections_in_segment(&self, seg_index: usize) -> Vec<&SHdrView> {
+        let Some(ph) = self.phentries.get(seg_index) else {
+            return vec![];
+        };
+
+        self.shentries
+            .iter()
+            .skip(1)
+            .filter(|sh| ph.contains_section(sh))
+            .collect()
+    }
+
+    /// Index of the first LOAD segment holding the section, `None` for
+    /// the sections that aren't loaded (e.g. `.symtab`)
+    pub fn segment_of_section(&self, name: &str) -> Option<usize> {
+        let sh = self.shentries.get(name)?;
+
+        self.phentries.iter().position(|ph| {
+            matches!(ph.ty, PhType::LOAD) && ph.contains_section(sh)
+        })
+    }
+
+    /// Memory permissions of the LOAD segment mapping `vaddr`
+    pub fn permissions_at_vaddr(&self, vaddr: u64) -> Option<&PFLAGS> {
+        self.phentries
+            .iter()
+            .find(|ph| {
+                matches!(ph.ty, PhType::LOAD)
+                    && vaddr >= ph.vaddr.0
+                    && vaddr - ph.vaddr.0 < ph.memsz
+            })
+            .map(|ph| &ph.flags)
+    }
+
+    /// Whether the entry point lands in an executable LOAD segment,
+    /// it's false for broken (or packed) binaries and for the files
+    /// without segments.
+    pub fn entry_is_executable(&self) -> bool {
+        self.permissions_at_vaddr(self.ehdr.entry().0)
+            .is_some_and(|flags| flags.has(PFlagBit::X))
+    }
+
+    /// LOAD segment whose file-backed range contains `vaddr`
+    fn load_segment_of(&self, vaddr: u64) -> Option<&PHdrView> {
+        self.phentries.iter().find(|ph| {
+            matches!(ph.ty, PhType::LOAD)
+                && vaddr >= ph.vaddr.0
+                && vaddr - ph.vaddr.0 < ph.filesz
+        })
+    }
+
+    /// Raw bytes of a section in the file, `None` for a NOBITS one
+    /// (e.g. `.bss`) which occupies no file space
+    pub fn section_data_raw(&self, name: &str) -> Option<&[u8]> {
+        self.sh_data(self.shentries.get(name)?)
+    }
+
+    /// Bytes of a section as [`Elf::section_data_raw`], decompressed for
+    /// an `SHF_COMPRESSED` section or a GNU `.zdebug_*` one.
+    ///
+    /// `None` also for a compression without its feature enabled
+    /// (`flate2` for zlib, `zstd` for zstd), or a corrupt one.
+    pub fn section_data(&self, name: &str) -> Option<Cow<'_, [u8]>> {
+        let sh = self.shentries.get(name)?;
+        let raw = self.sh_data(sh)?;
+
+        if sh.flags.has(SHFlagBit::Compressed) {
+            let ident = self.ehdr.ident();
+
+            decompress_section(raw, ident.class(), ident.data())
+                .map(Cow::Owned)
+        } else if name.starts_with(".zdebug") && raw.starts_with(b"ZLIB") {
+            /* "ZLIB", then the uncompressed size in big endian */
+            let size = SectionData::new(raw, EIData::MSB).read_u64(4)?;
+
+            inflate(raw.get(12..)?, size).map(Cow::Owned)
+        } else {
+            Some(Cow::Borrowed(raw))
+        }
+    }
+
+    /// Bounds-checked, endianness-aware reader over a section's bytes
+    pub fn section_cursor(&self, name: &str) -> Option<SectionData<'_>> {
+        let sh = self.shentries.get(name)?;
+
+        Some(SectionData::new(
+            self.sh_data(sh)?,
+            self.ehdr.ident().data(),
+        ))
+    }
+
+    /// Every section with its raw bytes as [`Elf::section_data_raw`], the
+    /// null one included. `None` of a NOBITS section (or one out of the
+    /// file range).
+    pub fn sections_with_data(
+        &self,
+    ) -> impl Iterator<Item = (&SHdrView, Option<&[u8]>)> {
+        self.shentries.iter().map(|sh| (sh, self.sh_data(sh)))
+    }
+
+    /// Number of dynamic symbols implied by `.gnu.hash`.
+    ///
+    /// It's the highest symbol index reachable from the buckets, following
+    /// its hash chain to the terminating entry (lowest bit set).
+    pub fn gnu_hash_symbol_count(&self) -> Option<usize> {
+        let hash = self.section_cursor(".gnu.hash")?;
+
+        let nbuckets = hash.read_u32(0)? as usize;
+        let symoffset = hash.read_u32(4)? as usize;
+        let bloom_size = hash.read_u32(8)? as usize;
+
+        let word_sz = match self.ehdr.ident().class() {
+            EIClass::Bit32 => 4,
+            _ => 8,
+        };
+
+        let buckets_off = 16 + bloom_size.checked_mul(word_sz)?;
+        let chain_off = buckets_off + nbuckets.checked_mul(4)?;
+
+        let mut last = 0;
+        for i in 0..nbuckets {
+            last = last.max(hash.read_u32(buckets_off + i * 4)? as usize);
+        }
+
+        if last < symoffset {
+            return Some(symoffset);
+        }
+
+        loop {
+            let chain = hash.read_u32(chain_off + (last - symoffset) * 4)?;
+
+            if chain & 1 == 1 {
+                return Some(last + 1);
+            }
+
+            last += 1;
+        }
+    }
+
+    /// File bytes of the section, `None` for NOBITS or out of file range
+    pub(crate) fn sh_data(&self, sh: &SHdrView) -> Option<&[u8]> {
+        if matches!(sh.ty, SHType::NOBITS) {
+            return None;
+        }
+
+        let start = usize::try_from(sh.offset.0).ok()?;
+        let end = start.checked_add(usize::try_from(sh.size).ok()?)?;
+
+        self.data.get(start..end)
+    }
+
+    /// The whole file image
+    pub(crate) fn raw(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// End offset of the last file range referenced by the headers
+    fn image_end(&self) -> usize {
+        let ehdr = &self.ehdr;
+
+        let mut end = *ehdr.elf_hdr_sz() as u64;
+
+        /* The real counts, of the extended numbering as well */
+        end = end.max(ehdr.prog_hdr_offset().0.saturating_add(
+            *ehdr.prog_hdr_tab_ent_sz() as u64 * self.phentries.len() as u64,
+        ));
+
+        end = end.max(ehdr.section_hdr_offset().0.saturating_add(
+            *ehdr.section_hdr_ent_sz() as u64 * self.shentries.0.len() as u64,
+        ));
+
+        for sh in self.shentries.0.iter() {
+            if !matches!(sh.ty, SHType::NOBITS) {
+                end = end.max(sh.offset.0.saturating_add(sh.size));
+            }
+        }
+
+        for ph in self.phentries.iter() {
+            end = end.max(ph.offset.saturating_add(ph.filesz));
+        }
+
+        usize::try_from(end).unwrap_or(usize::MAX)
+    }
+}
+
+
+impl SharedElf {
+    /// Back to an owned `Elf` for editing, copied only if it's still
+    /// shared by another clone
+    pub fn into_elf(self) -> Elf {
+        Arc::unwrap_or_clone(self.0)
+    }
+
+    /// Whether both are clones of the same one
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Arc::ptr_eq(&this.0, &other.0)
+    }
+}
+
+impl Deref for SharedElf {
+    type Target = Elf;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Elf> for SharedElf {
+    fn from(elf: Elf) -> Self {
+        elf.into_shared()
+    }
+}
+
+impl Debug for SharedElf {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&*self.0, f)
+    }
+}
+
+impl Serialize for SharedElf {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl Default for StripOptions {
+    fn default() -> Self {
+        Self {
+            symbols: true,
+            debug: true,
+            comment: true,
+        }
+    }
+}
+
+impl Backing {
+    /// Switch to owned storage (copy-on-write) for in-place edit
+    fn to_mut(&mut self) -> &mut Vec<u8> {
+        #[cfg(feature = "std")]
+        if let Self::Mmap(mmap) = self {
+            *self = Self::Owned(mmap.to_vec());
+        }
+
+        match self {
+            Self::Owned(vec) => vec,
+            #[cfg(feature = "std")]
+            Self::Mmap(_) => unreachable!(),
+        }
+    }
+}
+
+impl Deref for Backing {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            #[cfg(feature = "std")]
+            Self::Mmap(mmap) => mmap,
+            Self::Owned(vec) => vec,
+        }
+    }
+}
+
+impl Clone for Backing {
+    fn clone(&self) -> Self {
+        Self::Owned(self.to_vec())
+    }
+}
+
+impl ElfClass for Class32 {
+    type Hdr = E32Hdr;
+    type Phdr = E32Phdr;
+    type Shdr = E32Shdr;
+    type Sym = E32Sym;
+    type Dyn = E32Dyn;
+    type Rela = E32Rela;
+}
+
+impl ElfClass for Class64 {
+    type Hdr = E64Hdr;
+    type Phdr = E64Phdr;
+    type Shdr = E64Shdr;
+    type Sym = E64Sym;
+    type Dyn = E64Dyn;
+    type Rela = E64Rela;
+}
+
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Into Implementations
+
+impl From<EIdent> for EIdentView {
+    fn from(ident: EIdent) -> Self {
+        let magic_nums = MagicNums(ident.magic_nums());
+        let class = match ident.class {
+            1 => EIClass::Bit32,
+            2 => EIClass::Bit64,
+            _ => EIClass::Invalid,
+        };
+        let data = match ident.data {
+            1 => EIData::LSB,
+            2 => EIData::MSB,
+            _ => EIData::Invalid,
+        };
+
+        EIdentView {
+            magic_nums,
+            class,
+            data,
+            version: ident.version,
+            osabi: ident.osabi.into(),
+            abiversion: ident.abiversion,
+            pad: {
+                let [a, b, c, d, e, f] = ident.pad;
+                [a, b, c, d, e, f, ident.nident]
+            },
+            nident: ident.nident,
+        }
+    }
+}
+
+/// String table which the first section of the type `ty` links to,
+/// whatever the names of both are
+fn load_linked_strtab(
+    shentries: &SHEntries,
+    ty: SHType,
+    mmap: &[u8],
+) -> Option<StrTab> {
+    let sh = shentries.first_by_type(ty)?;
+    let strtab = shentries
+        .get_index(sh.link as usize)
+        .filter(|strtab| matches!(strtab.ty, SHType::STRtab))?;
+
+    file_range(mmap, strtab.offset.0, strtab.size)
+        .map(|raw| StrTab::new(raw.to_vec()))
+}
+
+fn load_symtab_from_sh<S: Raw + Into<E64Sym>>(
+    shentries: &SHEntries,
+    ty: SHType,
+    strtab: &StrTab,
+    ety: &EType,
+    mmap: &[u8],
+    endian: EIData,
+    opts: &LoadOptions,
+) -> Result<SymTab, ElfError> {
+    Ok(if let Some(sh) = shentries.first_by_type(ty) {
+        let sym_sz = size_of::<S>();
+        let sym_num = sh.size as usize / sym_sz;
+
+        let sec_off = sh.offset.0 as usize;
+
+        if let Some(limit) = opts.max_symbols {
+            check_limit("symbols", sym_num, limit)?;
+        }
+        check_table_fit("symbols", mmap, sec_off, sym_num, sym_sz)?;
+        let mut symentries = Vec::with_capacity(sym_num);
+
+        for i in 0..sym_num {
+            let raw = table_entry("symbols", mmap, sec_off, i, sym_sz)?;
+            let sym: S = deserialize_endian(raw, endian)?;
+            let sym: E64Sym = sym.into();
+
+            let name = if opts.lazy_sym_names {
+                String::new()
+            } else {
+                strtab.get(sym.name() as usize).unwrap_or_default()
+            };
+
+            let bind = SymBinding::load_from_info(sym.info());
+            let ty = SymType::load_from_info(sym.info());
+            let visi = SymVisi::load_from_other(sym.other());
+            let shndx = SID::from(sym.shndx());
+
+            let value = match ety {
+                _ if shndx == SID::Undef && sym.value() == 0 => {
+                    SymValue::Undefined
+                }
+                EType::REL => {
+                    if shndx == SID::Common {
+                        SymValue::Alignment(sym.value())
+                    } else {
+                        SymValue::SectionOffset(sym.value())
+                    }
+                }
+                _ => SymValue::VirAddr(Hex64(sym.value())),
+            };
+
+            let symview = SymView {
+                name,
+                name_idx: sym.name(),
+                strtab_idx: sh.link,
+                bind,
+                ty,
+                visi,
+                shndx,
+                value,
+                size: sym.size(),
+                version: None,
+            };
+
+            symentries.push(symview);
+        }
+
+        SymTab(symentries)
+    } else {
+        SymTab(vec![])
+    })
+
+}
+
+/// Read a pointer of the DWARF encoding `enc` at the cursor, of the data
+/// at the address `base`: `pcrel` is against the address of the field,
+/// `datarel` against `base`.
+fn read_eh_pointer(
+    cursor: &mut SectionData,
+    enc: u8,
+    base: u64,
+    is_64: bool,
+) -> Option<u64> {
+    let field = base.wrapping_add(cursor.position() as u64);
+
+    let val = match enc & 0x0f {
+        /* absptr */
+        0x00 if is_64 => cursor.next_u64()?,
+        0x00 => cursor.next_u32()?.into(),
+        0x01 => cursor.next_uleb128()?,
+        0x02 => cursor.next_u16()?.into(),
+        0x03 => cursor.next_u32()?.into(),
+        0x04 => cursor.next_u64()?,
+        0x09 => cursor.next_sleb128()? as u64,
+        0x0a => cursor.next_u16()? as i16 as u64,
+        0x0b => cursor.next_u32()? as i32 as u64,
+        0x0c => cursor.next_u64()?,
+        _ => return None,
+    };
+
+    match enc & 0xf0 {
+        0x00 => Some(val),
+        0x10 => Some(field.wrapping_add(val)),
+        0x30 => Some(base.wrapping_add(val)),
+        _ => None,
+    }
+}
+
+/// Walk the `namesz, descsz, type, name, desc` note entries, desc and
+/// the next entry start at the alignment (4, or 8 for some 64 bit notes).
+fn parse_notes(mut cursor: SectionData, align: usize) -> Vec<NoteView> {
+    let align = if align == 8 { 8 } else { 4 };
+    let pad =
+        |off: usize| off.checked_add(align - 1).map(|x| x & !(align - 1));
+
+    let mut notes = vec![];
+
+    while cursor.remaining() >= 12 {
+        let (Some(namesz), Some(descsz), Some(ty)) =
+            (cursor.next_u32(), cursor.next_u32(), cursor.next_u32())
+        else {
+            break;
+        };
+
+        let name_off = cursor.position();
+        let Some(desc_off) =
+            name_off.checked_add(namesz as usize).and_then(pad)
+        else {
+            break;
+        };
+        let Some(desc) = cursor.read_bytes(desc_off, descsz as usize) else {
+            break;
+        };
+
+        let name = cursor.read_bytes(name_off, namesz as usize).unwrap_or(&[]);
+        let name = name.split(|&b| b == 0).next().unwrap_or_default();
+
+        notes.push(NoteView {
+            name: String::from_utf8_lossy(name).into_owned(),
+            ty,
+            desc: desc.to_vec(),
+        });
+
+        let next = pad(desc_off + desc.len());
+        if next.and_then(|next| cursor.seek(next)).is_none() {
+            break;
+        }
+    }
+
+    notes
+}
+
+fn check_limit(
+    what: &'static str,
+    declared: usize,
+    limit: usize,
+) -> Result<(), ElfError> {
+    if declared > limit {
+        return Err(ElfError::LimitExceeded {
+            what,
+            declared,
+            limit,
+        });
+    }
+
+    Ok(())
+}
+
+/// Check that a table of `num` entries at `off` fits in the file
+fn check_table_fit(
+    what: &'static str,
+    mmap: &[u8],
+    off: usize,
+    num: usize,
+    ent_sz: usize,
+) -> Result<(), ElfError> {
+    let size = num.checked_mul(ent_sz);
+    let end = size.and_then(|size| size.checked_add(off));
+
+    if end.is_some_and(|end| end <= mmap.len()) {
+        return Ok(());
+    }
+
+    Err(ElfError::Truncated {
+        what,
+        offset: off,
+        size: size.unwrap_or(usize::MAX),
+        file_size: mmap.len(),
+    })
+}
+
+/// Bytes of the `i`th entry of a table at `off`
+fn table_entry<'a>(
+    what: &'static str,
+    mmap: &'a [u8],
+    off: usize,
+    i: usize,
+    ent_sz: usize,
+) -> Result<&'a [u8], ElfError> {
+    let start = i.checked_mul(ent_sz).and_then(|rel| rel.checked_add(off));
+
+    start
+        .and_then(|start| mmap.get(start..start.checked_add(ent_sz)?))
+        .ok_or(ElfError::Truncated {
+            what,
+            offset: off,
+            size: i.saturating_add(1).saturating_mul(ent_sz),
+            file_size: mmap.len(),
+        })
+}
+
+/// Pad with zeros to the alignment (0 and 1 mean no alignment)
+pub(crate) fn pad_to(buf: &mut Vec<u8>, align: u64) {
+    let len = buf.len().next_multiple_of(align.max(1) as usize);
+
+    buf.resize(len, 0);
+}
+
+/// Truncate a widened value back to a 32 bit field
+pub(crate) fn narrow(what: &'static str, val: u64) -> Result<u32, ElfError> {
+    u32::try_from(val).map_err(|_| ElfError::LimitExceeded {
+        what,
+        declared: val as usize,
+        limit: u32::MAX as usize,
+    })
+}
+
+fn put_u16(buf: &mut [u8], off: usize, val: u16, endian: EIData) {
+    let bytes = match endian {
+        EIData::MSB => val.to_be_bytes(),
+        _ => val.to_le_bytes(),
+    };
+
+    if let Some(dst) = buf.get_mut(off..off.saturating_add(2)) {
+        dst.copy_from_slice(&bytes);
+    }
+}
+
+fn put_u32(buf: &mut [u8], off: usize, val: u32, endian: EIData) {
+    let bytes = match endian {
+        EIData::MSB => val.to_be_bytes(),
+        _ => val.to_le_bytes(),
+    };
+
+    if let Some(dst) = buf.get_mut(off..off.saturating_add(4)) {
+        dst.copy_from_slice(&bytes);
+    }
+}
+
+fn put_u64(buf: &mut [u8], off: usize, val: u64, endian: EIData) {
+    let bytes = match endian {
+        EIData::MSB => val.to_be_bytes(),
+        _ => val.to_le_bytes(),
+    };
+
+    if let Some(dst) = buf.get_mut(off..off.saturating_add(8)) {
+        dst.copy_from_slice(&bytes);
+    }
+}
+
+/// Bytes of `[off, off + size)` in the file, `None` if it's out of range
+fn file_range(mmap: &[u8], off: u64, size: u64) -> Option<&[u8]> {
+    let start = usize::try_from(off).ok()?;
+    let end = start.checked_add(usize::try_from(size).ok()?)?;
+
+    mmap.get(start..end)
+}
+
+/// Decompress the data after the compression header (`Elf32_Chdr` or
+/// `Elf64_Chdr`) of an `SHF_COMPRESSED` section
+fn decompress_section(
+    raw: &[u8],
+    class: EIClass,
+    endian: EIData,
+) -> Option<Vec<u8>> {
+    const ELFCOMPRESS_ZLIB: u32 = 1;
+    const ELFCOMPRESS_ZSTD: u32 = 2;
+
+    let chdr = SectionData::new(raw, endian);
+    let ty = chdr.read_u32(0)?;
+
+    /* ch_type, (ch_reserved,) ch_size, ch_addralign */
+    let (size, hdr_sz) = match class {
+        EIClass::Bit32 => (u64::from(chdr.read_u32(4)?), 12),
+        _ => (chdr.read_u64(8)?, 24),
+    };
+    let data = raw.get(hdr_sz..)?;
+
+    match ty {
+        ELFCOMPRESS_ZLIB => inflate(data, size),
+        ELFCOMPRESS_ZSTD => unzstd(data, size),
+        _ => None,
+    }
+}
+
+/// Read up to one byte more than `size`, against a bomb, then it must be
+/// exactly the declared `size`.
+///
+/// The declared `size` is attacker-controlled too, beyond `MAX_RATIO`
+/// times the `len` of the compressed data it's refused before inflating
+/// (zlib can't go beyond about 1032:1).
+#[cfg(any(feature = "flate2", feature = "zstd"))]
+fn read_declared(
+    mut reader: impl std::io::Read,
+    len: usize,
+    size: u64,
+) -> Option<Vec<u8>> {
+    use std::io::Read;
+
+    const MAX_RATIO: u64 = 1032;
+
+    if size > (len as u64).saturating_mul(MAX_RATIO) {
+        return None;
+    }
+
+    let mut out = vec![];
+    reader
+        .by_ref()
+        .take(size.saturating_add(1))
+        .read_to_end(&mut out)
+        .ok()?;
+
+    (out.len() as u64 == size).then_some(out)
+}
+
+#[cfg(feature = "flate2")]
+fn inflate(data: &[u8], size: u64) -> Option<Vec<u8>> {
+    read_declared(flate2::read::ZlibDecoder::new(data), data.len(), size)
+}
+
+#[cfg(not(feature = "flate2"))]
+fn inflate(_data: &[u8], _size: u64) -> Option<Vec<u8>> {
+    None
+}
+
+#[cfg(feature = "zstd")]
+fn unzstd(data: &[u8], size: u64) -> Option<Vec<u8>> {
+    let decoder = zstd::stream::read::Decoder::new(data).ok()?;
+
+    read_declared(decoder, data.len(), size)
+}
+
+#[cfg(not(feature = "zstd"))]
+fn unzstd(_data: &[u8], _size: u64) -> Option<Vec<u8>> {
+    None
+}
+
+/// Deserialize the `n`th `T` of the table at `off`
+fn deserialize_nth<T: Raw>(
+    mmap: &[u8],
+    off: usize,
+    n: usize,
+    endian: EIData,
+) -> Option<T> {
+    let off = off.checked_add(n.checked_mul(size_of::<T>())?)?;
+
+    deserialize_at(mmap, off, endian)
+}
+
+/// Deserialize a `T` at `off`, `None` if it's out of range
+fn deserialize_at<T: Raw>(
+    mmap: &[u8],
+    off: usize,
+    endian: EIData,
+) -> Option<T> {
+    let raw = mmap.get(off..off.checked_add(size_of::<T>())?)?;
+
+    deserialize_endian(raw, endian).ok()
+}
+
+/// Deserialize the whole `raw` as a `T`, in the byte order of the file
+fn deserialize_endian<T: Raw>(
+    raw: &[u8],
+    endian: EIData,
+) -> Result<T, ElfError> {
+    let mut data = SectionData::new(raw, endian);
+
+    T::read(&mut data)
+        .filter(|_| data.remaining() == 0)
+        .ok_or_else(|| {
+            ElfError::Malformed(format!(
+                "{} bytes aren't a {}",
+                raw.len(),
+                type_name::<T>()
+            ))
+        })
+}
+
+/// Serialize a `T` in the byte order of the file
+pub(crate) fn serialize_endian<T: Raw>(val: &T, endian: EIData) -> Vec<u8> {
+    let mut out = vec![];
+    val.write(&mut out, endian);
+
+    out
+}
+
+fn load_rela_from_sh<R: Raw + Into<E64Rela>>(
+    shentries: &SHEntries,
+    mmap: &[u8],
+    endian: EIData,
+) -> Vec<(String, Vec<RelaView>)> {
+    let rela_sz = size_of::<R>();
+
+    shentries
+        .0
+        .iter()
+        .filter(|sh| matches!(sh.ty, SHType::RELA))
+        .map(|sh| {
+            let sec_off = sh.offset.0 as usize;
+            let num = sh.size as usize / rela_sz;
+
+            let entries = (0..num)
+                .map_while(|i| deserialize_nth::<R>(mmap, sec_off, i, endian))
+                .map(|rela| RelaView::from(rela.into()))
+                .collect();
+
+            (sh.name.clone(), entries)
+        })
+        .collect()
+}
+
+fn load_dyn_from_sh<D: Raw + Into<E64Dyn>>(
+    shentries: &SHEntries,
+    dynstr: &StrTab,
+    mmap: &[u8],
+    endian: EIData,
+) -> Vec<DynEntry> {
+    let Some(sh) = shentries.get(".dynamic") else {
+        return vec![];
+    };
+
+    let dyn_sz = size_of::<D>();
+    let sec_off = sh.offset.0 as usize;
+    let num = sh.size as usize / dyn_sz;
+
+    (0..num)
+        .map_while(|i| deserialize_nth::<D>(mmap, sec_off, i, endian))
+        .map(|dyn_| DynEntry::new(dyn_.into(), dynstr))
+        .take_while(|entry| entry.tag != DynTag::NULL)
+        .collect()
+}
+
+fn load_versym_from_sh(
+    shentries: &SHEntries,
+    mmap: &[u8],
+    endian: EIData,
+) -> Vec<u16> {
+    let Some(sh) = shentries.get(".gnu.version") else {
+        return vec![];
+    };
+
+    let sec_off = sh.offset.0 as usize;
+    let num = sh.size as usize / 2;
+
+    (0..num)
+        .map_while(|i| deserialize_nth(mmap, sec_off, i, endian))
+        .collect()
+}
+
+/// `nbucket, nchain`, then the buckets and the chain, all the words are
+/// 32-bit (as the linkers do but for Alpha and s390x)
+fn load_sysv_hash_from_sh(
+    shentries: &SHEntries,
+    mmap: &[u8],
+    endian: EIData,
+) -> Option<SysvHashTable> {
+    let sh = shentries.first_by_type(SHType::HASH)?;
+    let mut raw =
+        SectionData::new(file_range(mmap, sh.offset.0, sh.size)?, endian);
+
+    let nbucket = raw.next_u32()? as usize;
+    let nchain = raw.next_u32()? as usize;
+
+    /* The table sizes are checked against the section size first */
+    if raw.remaining() / 4 < nbucket.checked_add(nchain)? {
+        return None;
+    }
+
+    let buckets = (0..nbucket)
+        .map(|_| raw.next_u32())
+        .collect::<Option<Vec<_>>>()?;
+    let chain = (0..nchain)
+        .map(|_| raw.next_u32())
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(SysvHashTable { buckets, chain })
+}
+
+/// `nbuckets, symoffset, bloom_size, bloom_shift`, then the bloom filter
+/// words of the class size, the buckets and the chain to the section end
+fn load_gnu_hash_from_sh(
+    shentries: &SHEntries,
+    mmap: &[u8],
+    endian: EIData,
+    class: EIClass,
+) -> Option<GnuHashTable> {
+    let sh = shentries.first_by_type(SHType::GNUHASH)?;
+    let mut raw =
+        SectionData::new(file_range(mmap, sh.offset.0, sh.size)?, endian);
+
+    let nbuckets = raw.next_u32()? as usize;
+    let symoffset = raw.next_u32()?;
+    let bloom_size = raw.next_u32()? as usize;
+    let bloom_shift = raw.next_u32()?;
+    let bloom_word_bits = match class {
+        EIClass::Bit32 => 32,
+        _ => 64,
+    };
+
+    /* The table sizes are checked against the section size first */
+    if raw.remaining()
+        < bloom_size * (bloom_word_bits as usize / 8) + nbuckets * 4
+    {
+        return None;
+    }
+
+    let bloom = (0..bloom_size)
+        .map(|_| match class {
+            EIClass::Bit32 => raw.next_u32().map(u64::from),
+            _ => raw.next_u64(),
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let buckets = (0..nbuckets)
+        .map(|_| raw.next_u32())
+        .collect::<Option<Vec<_>>>()?;
+    let chain = core::iter::from_fn(|| raw.next_u32()).collect();
+
+    Some(GnuHashTable {
+        symoffset,
+        bloom_shift,
+        bloom_word_bits,
+        bloom,
+        buckets,
+        chain,
+    })
+}
+
+fn load_verdef_from_sh(
+    shentries: &SHEntries,
+    strtab: &StrTab,
+    mmap: &[u8],
+    endian: EIData,
+) -> Vec<VerDefView> {
+    let mut verdefs = vec![];
+
+    let Some(sh) = shentries.get(".gnu.version_d") else {
+        return verdefs;
+    };
+
+    let mut off = sh.offset.0 as usize;
+
+    for _ in 0..sh.info {
+        let Some(verdef) = deserialize_at::<E64Verdef>(mmap, off, endian)
+        else {
+            break;
+        };
+
+        let mut names = vec![];
+        let mut aux_off = off + verdef.aux() as usize;
+
+        for _ in 0..verdef.cnt() {
+            let Some(verdaux) =
+                deserialize_at::<E64Verdaux>(mmap, aux_off, endian)
+            else {
+                break;
+            };
+
+            let name = verdaux.name() as usize;
+            names.push(strtab.get(name).unwrap_or_default());
+            aux_off += verdaux.next() as usize;
+        }
+
+        let mut names = names.into_iter();
+
+        verdefs.push(VerDefView {
+            ndx: verdef.ndx(),
+            flags: verdef.flags(),
+            name: names.next().unwrap_or_default(),
+            parents: names.collect(),
+        });
+
+        if verdef.next() == 0 {
+            break;
+        }
+        off += verdef.next() as usize;
+    }
+
+    verdefs
+}
+
+fn load_verneed_from_sh(
+    shentries: &SHEntries,
+    strtab: &StrTab,
+    mmap: &[u8],
+    endian: EIData,
+) -> Vec<VerNeedView> {
+    let mut verneeds = vec![];
+
+    let Some(sh) = shentries.get(".gnu.version_r") else {
+        return verneeds;
+    };
+
+    let mut off = sh.offset.0 as usize;
+
+    for _ in 0..sh.info {
+        let Some(verneed) = deserialize_at::<E64Verneed>(mmap, off, endian)
+        else {
+            break;
+        };
+
+        let mut aux = vec![];
+        let mut aux_off = off + verneed.aux() as usize;
+
+        for _ in 0..verneed.cnt() {
+            let Some(vernaux) =
+                deserialize_at::<E64Vernaux>(mmap, aux_off, endian)
+            else {
+                break;
+            };
+
+            aux.push(VerNeedAuxView {
+                name: strtab.get(vernaux.name() as usize).unwrap_or_default(),
+                flags: vernaux.flags(),
+                other: vernaux.other(),
+            });
+            aux_off += vernaux.next() as usize;
+        }
+
+        verneeds.push(VerNeedView {
+            file: strtab.get(verneed.file() as usize).unwrap_or_default(),
+            aux,
+        });
+
+        if verneed.next() == 0 {
+            break;
+        }
+        off += verneed.next() as usize;
+    }
+
+    verneeds
+}
+
+impl Debug for Elf {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Elf")
+            .field("ehdr", &self.ehdr)
+            .field("phentries", &self.phentries)
+            // .field("shstrtab", &self.shstrtab)
+            .field("shentries", &self.shentries)
+            // .field("strtab", &self.strtab)
+            .field("symtab", &self.symtab)
+            .field("dynsym", &self.dynsym)
+            .field("dynamic", &self.dynamic)
+            .field("verdef", &self.verdef)
+            .field("verneed", &self.verneed)
+            .field("relocations", &self.relocations)
+            .finish()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, error::Error, io::Read};
+
+    use sha2::{Digest, Sha256};
+
+    use super::{deserialize_nth, Elf, LoadOptions, SharedElf, StripOptions};
+    use crate::{
+        data::{tests::assert_header_roundtrip, E64Sym},
+        error::Format,
+        view::{
+            ArmFloatAbi, DynTag, EFlagsDecoded, EIClass, EIData, EMachine,
+            EType, FileKind, GnuHashTable, GnuProperties, Hex64,
+            LinkInfoMeaning, MipsAbi, MipsArch, OsAbi, PFlagBit, PhType,
+            RelocX86_64, RelroStatus, SHFlagBit, SHType, SHdrView, SymType,
+            SymValue, SymView, SymVisi, SysvHashTable, PFLAGS, SHFLAGS, SID,
+        },
+        ElfError,
+    };
+
+    #[test]
+    fn load_32bit() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/arr32")?;
+
+        let ehdr = elf.ehdr();
+
+        assert!(matches!(ehdr.ident().class(), EIClass::Bit32));
+        assert!(matches!(ehdr.ty(), EType::REL));
+        assert_eq!(ehdr.section_hdr_offset().0, 1200);
+        assert_eq!(*ehdr.elf_hdr_sz(), 52);
+        assert_eq!(*ehdr.section_hdr_ent_sz(), 40);
+        assert_eq!(usize::from(*ehdr.section_str_tab_idx()), 15);
+        assert_eq!(elf.shentries().index_of(".symtab"), Some(13));
+
+        let sum = elf.symtab().0.iter().find(|sym| sym.name() == "sum");
+        let sum = sum.unwrap();
+
+        assert_eq!(sum.size(), &82);
+        assert!(matches!(sum.value(), SymValue::SectionOffset(0x15)));
+        assert_eq!(sum.section_name(&elf), Some(".text"));
+
+        /* Renamed in place, `.text` is the tail of `.rel.text` */
+        let mut elf = elf;
+        elf.rename_section(".comment", ".text")?;
+
+        let renamed = Elf::parse(&elf.to_bytes()?)?;
+        assert!(renamed.shentries().get(".comment").is_none());
+        assert_eq!(renamed.symtab().0.len(), 15);
+
+        Ok(())
+    }
+
+    #[test]
+    fn backing_shared_across_threads() -> Result<(), Box<dyn Error>> {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Elf>();
+
+        let mapped = Elf::load("./draft/arr")?;
+        let owned = Elf::parse(&std::fs::read("./draft/arr")?)?;
+
+        let data = std::thread::scope(|scope| {
+            let handle = scope.spawn(|| mapped.section_data(".data"));
+
+            handle.join().unwrap()
+        });
+
+        assert_eq!(data, owned.section_data(".data"));
+        assert_eq!(mapped.clone().to_bytes()?, owned.to_bytes()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn shared_elf_clones() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/hello")?;
+        let bytes = elf.to_bytes()?;
+
+        let shared = elf.into_shared();
+        let clones: Vec<SharedElf> = (0..4).map(|_| shared.clone()).collect();
+        assert!(clones.iter().all(|other| SharedElf::ptr_eq(&shared, other)));
+
+        let names = std::thread::scope(|scope| {
+            let handles: Vec<_> = clones
+                .into_iter()
+                .map(|elf| scope.spawn(move || elf.interpreter()))
+                .collect();
+
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+        assert!(names.iter().all(|name| *name == shared.interpreter()));
+
+        /* The last one unwraps without a copy */
+        assert_eq!(format!("{:?}", shared), format!("{:?}", *shared));
+        assert_eq!(shared.into_elf().to_bytes()?, bytes);
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_type_and_machine() -> Result<(), Box<dyn Error>> {
+        let mut bytes = std::fs::read("./draft/arr")?;
+        bytes[16..18].copy_from_slice(&0xfe01u16.to_le_bytes());
+        bytes[18..20].copy_from_slice(&0x1234u16.to_le_bytes());
+
+        let elf = Elf::parse(&bytes)?;
+
+        assert!(matches!(elf.ehdr().ty(), EType::Unknown(0xfe01)));
+        assert!(matches!(elf.ehdr().machine(), EMachine::Unknown(0x1234)));
+        assert_eq!(elf.to_bytes()?, bytes);
+
+        for (machine, expected) in [
+            (22u16, EMachine::S390),
+            (40, EMachine::ARM),
+            (183, EMachine::AARCH64),
+            (243, EMachine::RISCV),
+        ] {
+            bytes[18..20].copy_from_slice(&machine.to_le_bytes());
+            let elf = Elf::parse(&bytes)?;
+
+            assert_eq!(*elf.ehdr().machine(), expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn machine_metadata() -> Result<(), Box<dyn Error>> {
+        /* As the class and the layout of the x86 fixtures tell */
+        let hello = Elf::load("./draft/hello")?;
+        let machine = hello.ehdr().machine();
+        assert_eq!(machine.pointer_size(), Some(8));
+        assert!(hello
+            .phentries()
+            .iter()
+            .filter(|ph| matches!(ph.ty(), PhType::LOAD))
+            .all(|ph| Some(*ph.align()) == machine.default_page_size()));
+
+        let plt = hello.plt_entries();
+        assert_eq!(
+            plt[1].addr().0 - plt[0].addr().0,
+            machine.plt_entry_size().unwrap()
+        );
+
+        let arr32 = Elf::load("./draft/arr32")?;
+        assert_eq!(arr32.ehdr().machine().pointer_size(), Some(4));
+        assert_eq!(arr32.ehdr().machine().is_64bit_default(), Some(false));
+        assert_eq!(machine.is_64bit_default(), Some(true));
+
+        assert_eq!(EMachine::AARCH64.default_page_size(), Some(0x1_0000));
+        assert_eq!(EMachine::ARM.plt_entry_size(), Some(12));
+        assert_eq!(EMachine::RISCV.pointer_size(), None);
+        assert_eq!(EMachine::PPC64.plt_entry_size(), None);
+
+        let unknown = EMachine::Unknown(0x1234);
+        assert_eq!(unknown.pointer_size(), None);
+        assert_eq!(unknown.default_page_size(), None);
+        assert_eq!(unknown.plt_entry_size(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn section_type_round_trip() {
+        let gaps = [12, 13, 19, 30, 0x5fff_ffff];
+        let specials = [0x6000_0000, 0x6fff_fff6, 0x7000_0001, 0x8000_0000];
+
+        for val in (0..=18).chain(gaps).chain(specials) {
+            assert_eq!(u32::from(SHType::from(val)), val);
+        }
+
+        for val in gaps {
+            assert!(
+                matches!(SHType::from(val), SHType::Unknown(x) if x == val)
+            );
+        }
+        assert!(matches!(SHType::from(17), SHType::GROUP));
+        assert!(matches!(SHType::from(18), SHType::SYMtabSHNDX));
+        assert!(matches!(SHType::from(0x7000_0001), SHType::SPECPROC(_)));
+    }
+
+    #[test]
+    fn symbol_visibility() -> Result<(), Box<dyn Error>> {
+        /* The upper 6 bits of st_other aren't the visibility */
+        for other in 0..=u8::MAX {
+            assert_eq!(SymVisi::load_from_other(other) as u8, other & 0x3);
+        }
+
+        let elf = Elf::load("./draft/hello")?;
+
+        assert!(matches!(
+            elf.symbol("_init").unwrap().visi(),
+            SymVisi::Hidden
+        ));
+        assert!(matches!(
+            elf.symbol("main").unwrap().visi(),
+            SymVisi::Default
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn os_abi() -> Result<(), Box<dyn Error>> {
+        let mut bytes = std::fs::read("./draft/arr")?;
+        assert_eq!(Elf::parse(&bytes)?.ehdr().ident().osabi(), OsAbi::SysV);
+
+        for (raw, osabi) in [
+            (3, OsAbi::Linux),
+            (9, OsAbi::FreeBSD),
+            (64, OsAbi::ArmAeabi),
+            (255, OsAbi::Standalone),
+            (200, OsAbi::Other(200)),
+        ] {
+            bytes[7] = raw;
+            let elf = Elf::parse(&bytes)?;
+
+            assert_eq!(elf.ehdr().ident().osabi(), osabi);
+            assert_eq!(u8::from(osabi), raw);
+            assert_eq!(elf.to_bytes()?, bytes);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn decoded_header_flags() -> Result<(), Box<dyn Error>> {
+        let arr = Elf::load("./draft/arr")?;
+        assert_eq!(arr.ehdr().decoded_flags(), EFlagsDecoded::Raw(Hex64(0)));
+
+        /* e_machine and e_flags of the 32-bit header */
+        let mut bytes = std::fs::read("./draft/arr32")?;
+        let mut patch = |machine: u16, flags: u32| {
+            bytes[18..20].copy_from_slice(&machine.to_le_bytes());
+            bytes[36..40].copy_from_slice(&flags.to_le_bytes());
+
+            Elf::parse(&bytes).map(|elf| elf.ehdr().decoded_flags())
+        };
+
+        /* EABI 5, hard-float */
+        let EFlagsDecoded::Arm(arm) = patch(40, 0x0500_0400)? else {
+            panic!("not ARM flags");
+        };
+        assert_eq!(arm.eabi_version(), 5);
+        assert_eq!(arm.float_abi(), ArmFloatAbi::Hard);
+        assert!(!arm.be8());
+
+        /* The GNU (pre-EABI) ABI has no float bits */
+        let EFlagsDecoded::Arm(arm) = patch(40, 0x0000_0400)? else {
+            panic!("not ARM flags");
+        };
+        assert_eq!(arm.eabi_version(), 0);
+        assert_eq!(arm.float_abi(), ArmFloatAbi::Unspecified);
+
+        /* noreorder, pic, cpic, o32, mips32r2 */
+        let EFlagsDecoded::Mips(mips) = patch(8, 0x7000_1007)? else {
+            panic!("not MIPS flags");
+        };
+        assert_eq!(mips.abi(), MipsAbi::O32);
+        assert_eq!(mips.arch(), MipsArch::Mips32R2);
+        assert!(mips.noreorder() && mips.pic() && mips.cpic());
+
+        assert_eq!(patch(20, 0x1234)?, EFlagsDecoded::Raw(Hex64(0x1234)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn serialize_json() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/hello")?;
+        let json = serde_json::to_value(&elf)?;
+
+        assert_eq!(json["ehdr"]["machine"], "X86_64");
+        assert_eq!(json["ehdr"]["ident"]["osabi"], "SysV");
+        assert_eq!(json["ehdr"]["ident"]["magic_nums"], "7f 45 4c 46");
+        assert_eq!(json["ehdr"]["entry"], "0x0000000000001070");
+        assert_eq!(json["shentries"][0]["addr"], "0x0000000000000000");
+        assert_eq!(json["dynsym"][0]["value"], "Undefined");
+
+        let main = elf.symtab().iter().position(|sym| sym.name() == "main");
+        assert_eq!(
+            json["symtab"][main.unwrap()]["value"]["VirAddr"],
+            "0x0000000000001195"
+        );
+        assert_eq!(json["shentries"].as_array().unwrap().len(), 31);
+        assert!(json["symtab"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|sym| sym["name"] == "main"));
+        assert_eq!(json["dynamic"][0]["name"], "libc.so.6");
+
+        /* Neither the string tables nor the file image */
+        assert!(json.get("strtab").is_none());
+        assert!(json.get("data").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn compressed_sections() -> Result<(), Box<dyn Error>> {
+        let plain = Elf::load("./draft/arrdbg")?;
+        let debug_info = plain.section_data(".debug_info").unwrap();
+
+        let zlib = cfg!(feature = "flate2");
+        let zstd = cfg!(feature = "zstd");
+
+        for (name, section, enabled) in [
+            ("arrdbg.zlib", ".debug_info", zlib),
+            ("arrdbg.zlib-gnu", ".zdebug_info", zlib),
+            ("arrdbg.zstd", ".debug_info", zstd),
+        ] {
+            let elf = Elf::load(format!("./draft/{}", name))?;
+            let raw = elf.section_data_raw(section).unwrap();
+
+            assert_ne!(raw, &debug_info[..], "{}", name);
+
+            let data = elf.section_data(section);
+
+            if enabled {
+                assert_eq!(data.as_deref(), Some(&debug_info[..]), "{}", name);
+            } else {
+                assert_eq!(data, None, "{}", name);
+            }
+        }
+
+        let elf = Elf::load("./draft/arrdbg.zlib")?;
+        assert!(elf
+            .shentries()
+            .get(".debug_info")
+            .unwrap()
+            .flags()
+            .has(SHFlagBit::Compressed));
+        assert!(matches!(elf.section_data(".text"), Some(Cow::Borrowed(_))));
+
+        /* A ch_size far beyond what the data can inflate to */
+        let mut bytes = std::fs::read("./draft/arrdbg.zlib")?;
+        let sh = elf.shentries().get(".debug_info").unwrap();
+        let at = sh.offset().0 as usize + 8;
+        bytes[at..at + 8].copy_from_slice(&(sh.size() * 1033).to_le_bytes());
+
+        assert_eq!(Elf::parse(&bytes)?.section_data(".debug_info"), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn overlay_round_trip() -> Result<(), Box<dyn Error>> {
+        let mut elf = Elf::load("./draft/arr")?;
+        let image_len = elf.to_bytes()?.len() as u64;
+
+        assert!(elf.overlay().is_empty());
+        assert_eq!(elf.append_overlay(b"signed-by-me"), image_len);
+
+        assert_header_roundtrip(&elf.to_bytes()?);
+
+        let elf = Elf::parse(&elf.to_bytes()?)?;
+
+        assert_eq!(elf.overlay(), b"signed-by-me");
+
+        Ok(())
+    }
+
+    #[test]
+    fn section_cursor_reads() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/arr")?;
+
+        // int arr[10] = {1, 2, 3};
+        let data = elf.section_cursor(".data").unwrap();
+        assert_eq!(data.len(), 40);
+        assert_eq!(data.read_u32(4), Some(2));
+        assert_eq!(data.read_u32(40), None);
+
+        let comment = elf.section_cursor(".comment").unwrap();
+        assert!(comment.read_cstr(1).unwrap().starts_with("GCC"));
+
+        assert!(elf.section_cursor(".bss").is_none());
+
+        let comment = elf.section_data(".comment").unwrap();
+        assert_eq!(comment[0], 0);
+        assert_eq!(comment.last(), Some(&0));
+        assert_eq!(
+            &elf.section_data(".data").unwrap()[..8],
+            [1, 0, 0, 0, 2, 0, 0, 0]
+        );
+        assert_eq!(elf.section_data(".bss"), None);
+        assert_eq!(elf.section_data(".nonexistent"), None);
+
+        Ok(())
+    }
+
+    /// Minimal x86-64 core file: two PT_LOAD segments, the second one
+    /// is only partly dumped (`filesz < memsz`).
+    fn synth_core() -> Vec<u8> {
+        let mut buf = b"\x7fELF\x02\x01\x01".to_vec();
+        buf.resize(16, 0);
+
+        buf.extend(4u16.to_le_bytes()); // ET_CORE
+        buf.extend(62u16.to_le_bytes()); // EM_X86_64
+        buf.extend(1u32.to_le_bytes());
+        buf.extend(0u64.to_le_bytes()); // entry
+        buf.extend(64u64.to_le_bytes()); // phoff
+        buf.extend(0u64.to_le_bytes()); // shoff
+        buf.extend(0u32.to_le_bytes());
+        for half in [64u16, 56, 2, 64, 0, 0] {
+            buf.extend(half.to_le_bytes());
+        }
+
+        for (flags, offset, vaddr, filesz, memsz) in [
+            (6u32, 0xb0u64, 0x7fff_0000u64, 0x10u64, 0x10u64),
+            (5, 0xc0, 0x40_0000, 0x8, 0x1000),
+        ] {
+            buf.extend(1u32.to_le_bytes()); // PT_LOAD
+            buf.extend(flags.to_le_bytes());
+            for word in [offset, vaddr, 0, filesz, memsz, 0x1000] {
+                buf.extend(word.to_le_bytes());
+            }
+        }
+
+        buf.extend(b"ELFLIB-CORE-MARK");
+        buf.extend(0xf4c3_9090u64.to_le_bytes());
+
+        buf
+    }
+
+    #[test]
+    fn parse_matches_load() -> Result<(), Box<dyn Error>> {
+        for name in ["arr", "arr32", "libarr.so.1", "hello", "gonote"] {
+            let path = format!("./draft/{}", name);
+            let bytes = std::fs::read(&path)?;
+
+            /* As a blob embedded in some bigger buffer */
+            let mut archive = b"!<arch>\n".to_vec();
+            archive.extend(&bytes);
+            archive.extend(b"trailer");
+            let blob = &archive[8..8 + bytes.len()];
+
+            assert_eq!(
+                format!("{:?}", Elf::parse(blob)?),
+                format!("{:?}", Elf::load(&path)?),
+                "{}",
+                name
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn program_headers() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/hello")?;
+        let phentries = elf.phentries();
+
+        assert_eq!(
+            phentries.len(),
+            *elf.ehdr().prog_hdr_tab_ent_num() as usize
+        );
+
+        let phdr = &phentries[0];
+        assert!(matches!(phdr.ty(), PhType::PHDR));
+        assert_eq!(*phdr.offset(), elf.ehdr().prog_hdr_offset().0);
+        assert_eq!(*phdr.filesz(), 13 * 56);
+
+        let loads: Vec<_> = phentries
+            .iter()
+            .filter(|ph| matches!(ph.ty(), PhType::LOAD))
+            .collect();
+        assert_eq!(loads.len(), 4);
+        assert!(loads[1].flags().has(PFlagBit::X));
+        assert!(!loads[1].flags().has(PFlagBit::W));
+        assert!(loads[3].flags().has(PFlagBit::W));
+
+        assert_eq!(
+            loads
+                .iter()
+                .map(|ph| ph.flags().letters())
+                .collect::<Vec<_>>(),
+            ["R  ", "R E", "R  ", "RW "]
+        );
+        assert_eq!(PFLAGS::from(0x7).letters(), "RWE");
+        assert_eq!(PFLAGS::from(0x0010_0000).letters(), "   ");
+        assert!(loads[3].memsz() > loads[3].filesz());
+        assert_eq!(*loads[3].align(), 0x1000);
+
+        /* PT_GNU_STACK */
+        assert!(phentries
+            .iter()
+            .any(|ph| matches!(ph.ty(), PhType::GNUSTACK)));
+
+        assert!(format!("{:?}", elf).contains("phentries: [PHdrView"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn program_interpreter() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/hello")?;
+        assert_eq!(
+            elf.interpreter().as_deref(),
+            Some("/lib64/ld-linux-x86-64.so.2")
+        );
+
+        /* Trimmed at the first NUL */
+        let interp = elf.shentries().get(".interp").unwrap();
+        let mut buf = elf.raw().to_vec();
+        buf[interp.offset().0 as usize + 4] = 0;
+        assert_eq!(Elf::parse(&buf)?.interpreter().as_deref(), Some("/lib"));
+
+        assert_eq!(Elf::load("./draft/libarr.so.1")?.interpreter(), None);
+        assert_eq!(Elf::load("./draft/arr")?.interpreter(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn thread_local_storage() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/libtls.so.1")?;
+        let tls = elf.tls_info().unwrap();
+
+        /* .tdata of `counter` and `tag`, .tbss of `slots` */
+        assert_eq!(*tls.filesz(), 7);
+        assert_eq!(*tls.memsz(), 0x50);
+        assert_eq!(*tls.align(), 0x10);
+        assert_eq!(
+            tls.vaddr().0,
+            elf.shentries().get(".tdata").unwrap().addr.0
+        );
+
+        let mut syms: Vec<_> = tls
+            .symbols()
+            .iter()
+            .map(|sym| (sym.name().as_str(), *sym.size()))
+            .collect();
+        syms.sort();
+        assert_eq!(syms, [("counter", 4), ("slots", 64), ("tag", 3)]);
+
+        assert_eq!(Elf::load("./draft/hello")?.tls_info(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn lazy_symbol_names() -> Result<(), Box<dyn Error>> {
+        for name in ["hello", "libarr.so.1", "arr32"] {
+            let bytes = std::fs::read(format!("./draft/{}", name))?;
+            let eager = Elf::parse(&bytes)?;
+            let lazy = Elf::parse_lazy(&bytes)?;
+
+            assert!(lazy.dynsym().len() > 1 || lazy.symtab().len() > 1);
+            assert_eq!(eager.shentries(), lazy.shentries());
+
+            for (eager_tab, lazy_tab) in [
+                (eager.symtab(), lazy.symtab()),
+                (eager.dynsym(), lazy.dynsym()),
+            ] {
+                assert_eq!(eager_tab.len(), lazy_tab.len());
+
+                for (e, l) in eager_tab.iter().zip(lazy_tab) {
+                    assert!(l.name().is_empty());
+                    assert_eq!(lazy.resolve_sym_name(l), e.name());
+                    assert_eq!(eager.resolve_sym_name(e), e.name());
+                }
+            }
+        }
+
+        /* A clone of a dynamic symbol is still resolved in .dynstr */
+        let bytes = std::fs::read("./draft/libarr.so.1")?;
+        let lazy = Elf::parse_lazy(&bytes)?;
+        let sum = lazy
+            .dynsym()
+            .iter()
+            .find(|sym| lazy.resolve_sym_name(sym) == "sum")
+            .cloned()
+            .unwrap();
+
+        assert_eq!(lazy.resolve_sym_name(&sum), "sum");
+
+        Ok(())
+    }
+
+    #[test]
+    fn core_dump_notes() -> Result<(), Box<dyn Error>> {
+        let note = |ty: u32, desc: &[u8]| {
+            let mut raw = vec![];
+            raw.extend(5u32.to_le_bytes());
+            raw.extend((desc.len() as u32).to_le_bytes());
+            raw.extend(ty.to_le_bytes());
+            raw.extend(b"CORE\0\0\0\0");
+            raw.extend(desc);
+            raw
+        };
+
+        /* The faulting thread and another one */
+        let prstatus = |pid: u32, sig: u16, rip: u64| {
+            let mut desc = vec![0; 336];
+            desc[12..14].copy_from_slice(&sig.to_le_bytes());
+            desc[32..36].copy_from_slice(&pid.to_le_bytes());
+            for (i, reg) in (0..27u64).enumerate() {
+                let val = if i == 16 { rip } else { reg };
+                desc[112 + i * 8..][..8].copy_from_slice(&val.to_le_bytes());
+            }
+            note(1, &desc)
+        };
+        let mut psinfo = vec![0; 136];
+        psinfo[24..28].copy_from_slice(&4242u32.to_le_bytes());
+        psinfo[40..45].copy_from_slice(b"crash");
+        psinfo[56..74].copy_from_slice(b"./crash arg1 arg2 ");
+
+        let mut notes = prstatus(4242, 11, 0x40_1012);
+        notes.extend(note(3, &psinfo));
+        notes.extend(prstatus(4243, 0, 0x40_2000));
+
+        let mut buf = synth_core()[..64].to_vec();
+        buf[56..58].copy_from_slice(&1u16.to_le_bytes()); // e_phnum
+        buf.extend(4u32.to_le_bytes()); // PT_NOTE
+        buf.extend(0u32.to_le_bytes());
+        for word in [120, 0, 0, notes.len() as u64, 0, 4] {
+            buf.extend(word.to_le_bytes());
+        }
+        buf.extend(&notes);
+
+        let elf = Elf::parse(&buf)?;
+        let info = elf.core_info().unwrap();
+
+        assert_eq!(*info.pid(), 4242);
+        assert_eq!(*info.signal(), 11);
+        assert_eq!(info.command(), "crash");
+        assert_eq!(info.args(), "./crash arg1 arg2");
+
+        let threads = info.threads();
+        assert_eq!(threads.len(), 2);
+        assert_eq!(*threads[1].pid(), 4243);
+        assert_eq!(*threads[1].signal(), 0);
+        assert_eq!(threads[0].registers().len(), 27);
+        assert_eq!(threads[0].register("rip"), Some(0x40_1012));
+        assert_eq!(threads[1].register("rip"), Some(0x40_2000));
+        assert_eq!(threads[0].register("rbx"), Some(5));
+        assert_eq!(threads[0].register("gs"), Some(26));
+        assert_eq!(threads[0].register("xmm0"), None);
+
+        /* Not a core dump, or one without the notes */
+        assert_eq!(Elf::load("./draft/hello")?.core_info(), None);
+        assert_eq!(Elf::parse(&synth_core())?.core_info(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn pie_or_shared_object() -> Result<(), Box<dyn Error>> {
+        let hello = Elf::load("./draft/hello")?;
+        assert!(hello.is_pie());
+        assert_eq!(hello.file_kind(), Some(FileKind::Pie));
+
+        let lib = Elf::load("./draft/libarr.so.1")?;
+        assert!(!lib.is_pie());
+        assert_eq!(lib.file_kind(), Some(FileKind::SharedObject));
+
+        let arr = Elf::load("./draft/arr")?;
+        assert_eq!(arr.file_kind(), Some(FileKind::Relocatable));
+        let core = Elf::parse(&synth_core())?;
+        assert_eq!(core.file_kind(), Some(FileKind::Core));
+
+        /* The DF_1_PIE is enough without the INTERP */
+        let mut buf = hello.raw().to_vec();
+        let interp = 64
+            + 56 * hello
+                .phentries()
+                .iter()
+                .position(|ph| matches!(ph.ty(), PhType::INTERP))
+                .unwrap();
+        buf[interp..interp + 4].copy_from_slice(&0u32.to_le_bytes());
+        let elf = Elf::parse(&buf)?;
+        assert_eq!(elf.interpreter(), None);
+        assert!(elf.is_pie());
+
+        /* e_type */
+        buf[16..18].copy_from_slice(&2u16.to_le_bytes());
+        let elf = Elf::parse(&buf)?;
+        assert!(!elf.is_pie());
+        assert_eq!(elf.file_kind(), Some(FileKind::Executable));
+
+        buf[16..18].copy_from_slice(&0xfe00u16.to_le_bytes());
+        assert_eq!(Elf::parse(&buf)?.file_kind(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn section_to_segment_mapping() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/hello")?;
+        let names = |i| {
+            elf.sections_in_segment(i)
+                .into_iter()
+                .map(|sh| sh.name().as_str())
+                .collect::<Vec<_>>()
+        };
+
+        /* PHDR, INTERP and the RW LOAD with the .bss */
+        assert!(names(0).is_empty());
+        assert_eq!(names(1), [".interp"]);
+        assert_eq!(
+            names(5),
+            [
+                ".preinit_array",
+                ".init_array",
+                ".fini_array",
+                ".dynamic",
+                ".got",
+                ".data",
+                ".bss"
+            ]
+        );
+        assert!(names(13).is_empty());
+
+        assert_eq!(elf.segment_of_section(".interp"), Some(2));
+        assert_eq!(elf.segment_of_section(".text"), Some(3));
+        assert_eq!(elf.segment_of_section(".dynamic"), Some(5));
+        assert_eq!(elf.segment_of_section(".bss"), Some(5));
+        assert_eq!(elf.segment_of_section(".comment"), None);
+        assert_eq!(elf.segment_of_section(".nosuch"), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn big_endian() -> Result<(), Box<dyn Error>> {
+        let mut buf = b"\x7fELF\x02\x02\x01".to_vec();
+        buf.resize(16, 0);
+
+        buf.extend(2u16.to_be_bytes()); // ET_EXEC
+        buf.extend(21u16.to_be_bytes()); // EM_PPC64
+        buf.extend(1u32.to_be_bytes());
+        buf.extend(0x1000_0100u64.to_be_bytes()); // entry
+        buf.extend(64u64.to_be_bytes()); // phoff
+        buf.extend(0u64.to_be_bytes()); // shoff
+        buf.extend(0u32.to_be_bytes());
+        for half in [64u16, 56, 1, 64, 0, 0] {
+            buf.extend(half.to_be_bytes());
+        }
+
+        buf.extend(1u32.to_be_bytes()); // PT_LOAD
+        buf.extend(5u32.to_be_bytes()); // R + X
+        for word in [0u64, 0x1000_0000, 0, 0x200, 0x200, 0x1_0000] {
+            buf.extend(word.to_be_bytes());
+        }
+        buf.resize(0x200, 0);
+
+        let elf = Elf::parse(&buf)?;
+
+        assert_eq!(elf.ehdr().entry().0, 0x1000_0100);
+        assert!(matches!(elf.ehdr().machine(), EMachine::PPC64));
+        assert!(matches!(elf.ehdr().ty(), EType::EXEC));
+        assert_eq!(elf.phentries()[0].vaddr().0, 0x1000_0000);
+        assert!(elf.entry_is_executable());
+
+        let mut buf = b"\x7fELF\x01\x02\x01".to_vec();
+        buf.resize(16, 0);
+
+        buf.extend(2u16.to_be_bytes()); // ET_EXEC
+        buf.extend(8u16.to_be_bytes()); // EM_MIPS
+        buf.extend(1u32.to_be_bytes());
+        buf.extend(0x40_0120u32.to_be_bytes()); // entry
+        buf.extend([0; 12]); // phoff, shoff, flags
+        for half in [52u16, 32, 0, 40, 0, 0] {
+            buf.extend(half.to_be_bytes());
+        }
+
+        let elf = Elf::parse(&buf)?;
+
+        assert_eq!(elf.ehdr().entry().0, 0x40_0120);
+        assert!(matches!(elf.ehdr().machine(), EMachine::MIPS));
+
+        Ok(())
+    }
+
+    #[test]
+    fn rename_section_round_trip() -> Result<(), Box<dyn Error>> {
+        let mut elf = Elf::load("./draft/arr")?;
+        let text = elf.section_cursor(".text").unwrap().raw().to_vec();
+
+        elf.append_overlay(b"tail");
+        elf.rename_section(".comment", ".hidden")?;
+
+        /* The name index follows the edit */
+        let hidden = elf.shentries().index_of(".hidden").unwrap();
+        assert_eq!(elf.shentries().get_index(hidden).unwrap().name, ".hidden");
+        assert!(elf.shentries().get(".comment").is_none());
+
+        assert!(matches!(
+            elf.rename_section(".nope", ".x"),
+            Err(ElfError::SectionNotFound(_))
+        ));
+
+        let renamed = Elf::parse(&elf.to_bytes()?)?;
+
+        assert!(renamed.shentries().get(".comment").is_none());
+        assert!(renamed
+            .section_cursor(".hidden")
+            .unwrap()
+            .read_cstr(1)
+            .unwrap()
+            .starts_with("GCC"));
+        assert_eq!(renamed.section_cursor(".text").unwrap().raw(), text);
+        assert_eq!(renamed.overlay(), b"tail");
+
+        /* `.text` is the tail of `.rela.text`, nothing to append */
+        let mut elf = Elf::load("./draft/arr")?;
+        let shstrtab = elf.shstrtab.as_bytes().to_vec();
+
+        elf.rename_section(".comment", ".text")?;
+
+        assert_eq!(elf.shstrtab.as_bytes(), shstrtab);
+        assert_eq!(elf.shentries().index_of(".text"), Some(1));
+
+        let renamed = Elf::parse(&elf.to_bytes()?)?;
+        assert!(renamed.shentries().get(".comment").is_none());
+        assert_eq!(renamed.shentries().index_of(".text"), Some(1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn entry_executable() -> Result<(), Box<dyn Error>> {
+        let hello = Elf::load("./draft/hello")?;
+
+        assert!(hello.entry_is_executable());
+        assert!(!Elf::load("./draft/arr")?.entry_is_executable());
+        assert!(!Elf::parse(&synth_core())?.entry_is_executable());
+
+        /* Redirect e_entry into .data */
+        let data = hello.shentries().get(".data").unwrap().addr().0;
+        let mut bytes = hello.to_bytes()?;
+        bytes[24..32].copy_from_slice(&data.to_le_bytes());
+
+        assert!(!Elf::parse(&bytes)?.entry_is_executable());
+
+        Ok(())
+    }
+
+    #[test]
+    fn go_build_id() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/gonote")?;
+
+        assert_eq!(
+            elf.go_build_id().as_deref(),
+            Some("Zs6iJ1Yw0MgbSTCUPQzE/9yRlEd2Crn3VFmNy7Dd1")
+        );
+        assert_eq!(Elf::load("./draft/hello")?.go_build_id(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn notes_and_build_id() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/hello")?;
+        let notes = elf.notes();
+
+        assert_eq!(
+            notes
+                .iter()
+                .map(|note| (note.name().as_str(), *note.ty()))
+                .collect::<Vec<_>>(),
+            [("GNU", 5), ("GNU", 3), ("GNU", 1)]
+        );
+        assert_eq!(notes[2].desc()[..8], [0, 0, 0, 0, 3, 0, 0, 0]);
+        assert_eq!(
+            elf.build_id().as_deref(),
+            Some("80e5a88f4e25cf0499d9d48df6d0073c80c076f5")
+        );
+
+        /* Without sections, from the NOTE segments */
+        let mut bytes = std::fs::read("./draft/hello")?;
+        bytes[60..62].copy_from_slice(&[0, 0]);
+        let elf = Elf::parse(&bytes)?;
+
+        assert_eq!(elf.notes().len(), notes.len());
+        assert_eq!(elf.build_id(), None);
+
+        assert_eq!(Elf::load("./draft/arr")?.build_id(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn extended_numbering() -> Result<(), Box<dyn Error>> {
+        const NUM: usize = 0xff05;
+
+        /* The section header table moved to the end and padded to NUM
+         * entries, the counts and shstrndx escaped into the section 0 */
+        let mut bytes = std::fs::read("./draft/hello")?;
+        let hello = Elf::parse(&bytes)?;
+        let shoff = hello.ehdr().section_hdr_offset().0 as usize;
+        let shnum = hello.shentries().len();
+        let mut shdrs = bytes[shoff..shoff + shnum * 64].to_vec();
+
+        shdrs[32..40].copy_from_slice(&(NUM as u64).to_le_bytes());
+        shdrs[40..44].copy_from_slice(&30u32.to_le_bytes());
+        shdrs[44..48].copy_from_slice(&13u32.to_le_bytes());
+        shdrs.resize(NUM * 64, 0);
+
+        bytes.resize(bytes.len().next_multiple_of(8), 0);
+        let new_shoff = bytes.len() as u64;
+        bytes.extend_from_slice(&shdrs);
+        bytes[0x28..0x30].copy_from_slice(&new_shoff.to_le_bytes());
+        bytes[0x38..0x3a].copy_from_slice(&0xffffu16.to_le_bytes());
+        bytes[0x3c..0x3e].copy_from_slice(&0u16.to_le_bytes());
+        bytes[0x3e..0x40].copy_from_slice(&0xffffu16.to_le_bytes());
+
+        let elf = Elf::parse(&bytes)?;
+
+        assert_eq!(elf.shentries().len(), NUM);
+        assert_eq!(elf.phentries().len(), 13);
+        assert_eq!(elf.phentries(), hello.phentries());
+        assert_eq!(elf.shentries().index_of(".shstrtab"), Some(30));
+        assert_eq!(elf.symbol("main"), hello.symbol("main"));
+        assert_eq!(elf.to_bytes()?, bytes);
+
+        let header = elf.as_readelf_header();
+
+        assert!(header.contains("headers:         65535 (13)\n"));
+        assert!(header.contains("headers:         0 (65285)\n"));
+        assert!(header.contains("index: 65535 (30)\n"));
+
+        /* The count in the section 0 follows a removal */
+        let mut removed = elf.clone();
+        removed.remove_section(".comment")?;
+        let removed = Elf::parse(&removed.to_bytes()?)?;
+
+        assert_eq!(removed.shentries().len(), NUM - 1);
+        assert!(removed.shentries().get(".comment").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn load_options_limits() -> Result<(), Box<dyn Error>> {
+        let bytes = Elf::load("./draft/hello")?.to_bytes()?;
+        let limited =
+            |opts: LoadOptions| match Elf::parse_with_options(&bytes, &opts) {
+                Err(err) => matches!(err, ElfError::LimitExceeded { .. }),
+                Ok(_) => false,
+            };
+
+        assert!(limited(LoadOptions {
+            max_sections: Some(4),
+            ..Default::default()
+        }));
+        assert!(limited(LoadOptions {
+            max_symbols: Some(4),
+            ..Default::default()
+        }));
+        assert!(limited(LoadOptions {
+            max_file_size: Some(4096),
+            ..Default::default()
+        }));
+        assert!(!limited(LoadOptions {
+            max_sections: Some(64),
+            max_symbols: Some(256),
+            max_file_size: Some(1 << 20),
+            ..Default::default()
+        }));
+
+        /* Declare 0xffff sections for a file too small to contain them,
+         * it's truncated rather than over a limit */
+        let mut bytes = Elf::load("./draft/arr")?.to_bytes()?;
+        bytes[60..62].copy_from_slice(&0xffffu16.to_le_bytes());
+
+        match Elf::parse(&bytes) {
+            Err(err) => assert!(matches!(
+                err,
+                ElfError::Truncated {
+                    what: "sections",
+                    size: 0x3f_ffc0,
+                    ..
+                }
+            )),
+            _ => panic!("should be truncated"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn interpret_link_info() -> Result<(), Box<dyn Error>> {
+        let meaning_of = |elf: &Elf, name: &str| {
+            let sh = elf.shentries().get(name).unwrap();
+
+            format!("{:?}", sh.interpret_link_info(elf))
+        };
+
+        let elf = Elf::load("./draft/arr")?;
+
+        assert_eq!(
+            elf.shentries()
+                .get(".rela.text")
+                .unwrap()
+                .interpret_link_info(&elf),
+            LinkInfoMeaning::Relocates {
+                symtab: 9,
+                target: Some(1)
+            }
+        );
+        assert_eq!(
+            meaning_of(&elf, ".symtab"),
+            "SymbolTable { strtab: 10, first_nonlocal: 4 }"
+        );
+        assert_eq!(meaning_of(&elf, ".text"), "None");
+
+        let elf = Elf::load("./draft/libarr.so.1")?;
+
+        assert_eq!(meaning_of(&elf, ".gnu.hash"), "SymbolsOf(3)");
+        assert_eq!(meaning_of(&elf, ".gnu.version"), "SymbolsOf(3)");
+        assert_eq!(
+            meaning_of(&elf, ".gnu.version_d"),
+            "Versions { strtab: 4, count: 3 }"
+        );
+        assert_eq!(
+            meaning_of(&elf, ".rela.dyn"),
+            "Relocates { symtab: 3, target: None }"
+        );
+        assert_eq!(meaning_of(&elf, ".dynamic"), "StringTable(4)");
+
+        Ok(())
+    }
+
+    #[test]
+    fn signed_addends() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/arr")?;
+
+        let (name, text_relas) = &elf.relocations()[0];
+
+        assert_eq!(name, ".rela.text");
+        assert_eq!(text_relas.len(), 4);
+        assert!(text_relas.iter().all(|rela| rela.addend() == -4));
+        assert!(format!("{:?}", text_relas[0]).contains("addend: -4"));
+
+        let (_, eh_frame_relas) = &elf.relocations()[1];
+
+        assert_eq!(eh_frame_relas[1].addend(), 0xf);
+
+        let arr = elf.symtab().0.iter().find(|sym| sym.name() == "arr");
+
+        assert_eq!(arr.unwrap().value().as_signed(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn relocation_symbols() -> Result<(), Box<dyn Error>> {
+        let symbols_of = |elf: &Elf, section: &str| -> Vec<Option<String>> {
+            let (_, relas) = elf
+                .relocations()
+                .iter()
+                .find(|(name, _)| name == section)
+                .unwrap();
+
+            relas
+                .iter()
+                .map(|rela| elf.relocation_symbol(section, rela))
+                .map(|sym| sym.map(|sym| sym.name().clone()))
+                .collect()
+        };
+
+        let hello = Elf::load("./draft/hello")?;
+        let plt = symbols_of(&hello, ".rela.plt");
+
+        assert_eq!(
+            plt,
+            ["__stack_chk_fail", "__strcpy_chk", "__printf_chk"]
+                .map(|name| Some(name.to_owned()))
+        );
+
+        let dyn_ = symbols_of(&hello, ".rela.dyn");
+
+        assert!(dyn_[..6].iter().all(Option::is_none));
+        assert_eq!(dyn_[6].as_deref(), Some("__libc_start_main"));
+
+        /* Against `.symtab` of a relocatable object */
+        let arr = Elf::load("./draft/arr")?;
+        let text = symbols_of(&arr, ".rela.text");
+
+        assert!(text.contains(&Some("arr".to_owned())));
+        assert!(text.contains(&Some("counter".to_owned())));
+
+        Ok(())
+    }
+
+    #[test]
+    fn eh_frame_header() -> Result<(), Box<dyn Error>> {
+        let hello = Elf::load("./draft/hello")?;
+        let hdr = hello.eh_frame_hdr().unwrap();
+        let eh_frame = hello.shentries().get(".eh_frame").unwrap().addr.0;
+
+        /* pcrel|sdata4, udata4, datarel|sdata4 */
+        assert_eq!(*hdr.version(), 1);
+        assert_eq!(
+            (
+                *hdr.eh_frame_ptr_enc(),
+                *hdr.fde_count_enc(),
+                *hdr.table_enc()
+            ),
+            (0x1b, 0x03, 0x3b)
+        );
+        assert_eq!(hdr.eh_frame_ptr().0, eh_frame);
+
+        /* As the FDEs of `readelf -wf`, sorted by the location */
+        let table: Vec<_> = hdr
+            .table()
+            .iter()
+            .map(|(loc, fde)| (loc.0, fde.0 - eh_frame))
+            .collect();
+        assert_eq!(table.len(), 10);
+        assert_eq!(
+            table[..3],
+            [(0x1020, 0x48), (0x1060, 0x70), (0x1070, 0x18)]
+        );
+        assert!(table.windows(2).all(|pair| pair[0].0 < pair[1].0));
+
+        let main = hello.symtab().iter().find(|sym| sym.name() == "main");
+        let SymValue::VirAddr(main) = main.unwrap().value() else {
+            panic!("main should have an address");
+        };
+        assert_eq!(hdr.find_fde(main.0 + 8), Some(Hex64(eh_frame + 0xa4)));
+        assert_eq!(hdr.find_fde(0x1000), None);
+
+        /* From the segment, without the section headers */
+        let mut bytes = hello.to_bytes()?;
+        bytes[0x28..0x30].fill(0);
+        bytes[0x3c..0x40].fill(0);
+        assert_eq!(Elf::parse(&bytes)?.eh_frame_hdr(), Some(hdr));
+
+        assert_eq!(Elf::load("./draft/arr")?.eh_frame_hdr(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn plt_stubs() -> Result<(), Box<dyn Error>> {
+        let hello = Elf::load("./draft/hello")?;
+        let plt = hello.plt_entries();
+
+        let names: Vec<_> =
+            plt.iter().map(|ent| ent.symbol().as_str()).collect();
+        assert_eq!(
+            names,
+            ["__stack_chk_fail", "__strcpy_chk", "__printf_chk"]
+        );
+
+        /* Each stub is `jmp *got(%rip)` through its GOT slot */
+        for (i, ent) in plt.iter().enumerate() {
+            assert_eq!(*ent.index(), i);
+            assert_eq!(ent.addr().0, 0x1030 + 16 * i as u64);
+
+            let stub = hello.data_at_vaddr(ent.addr().0, 6).unwrap();
+            assert_eq!(stub[..2], [0xff, 0x25]);
+
+            let disp = i32::from_le_bytes(stub[2..].try_into()?);
+            assert_eq!(
+                (ent.addr().0 + 6).wrapping_add_signed(disp.into()),
+                ent.got().0
+            );
+        }
+
+        assert!(Elf::load("./draft/arr")?.plt_entries().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn reloc_type_names() -> Result<(), Box<dyn Error>> {
+        let names_of = |elf: &Elf, section: &str| -> Vec<&'static str> {
+            let (_, relas) = elf
+                .relocations()
+                .iter()
+                .find(|(name, _)| name == section)
+                .unwrap();
+
+            relas
+                .iter()
+                .filter_map(|rela| rela.reloc_type_name(elf))
+                .collect()
+        };
+
+        let hello = Elf::load("./draft/hello")?;
+        let dyn_ = names_of(&hello, ".rela.dyn");
+
+        assert_eq!(dyn_.len(), 11);
+        assert_eq!(dyn_[0], "R_X86_64_RELATIVE");
+        assert_eq!(dyn_[10], "R_X86_64_GLOB_DAT");
+        assert_eq!(names_of(&hello, ".rela.plt"), ["R_X86_64_JUMP_SLOT"; 3]);
+
+        let arr = Elf::load("./draft/arr")?;
+        assert_eq!(names_of(&arr, ".rela.text"), ["R_X86_64_PC32"; 4]);
+
+        assert_eq!(RelocX86_64::from(1).name(), Some("R_X86_64_64"));
+        assert_eq!(RelocX86_64::from(42), RelocX86_64::REXGOTPCRELX);
+        assert_eq!(RelocX86_64::from(40).name(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn gnu_section_flags() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/flags")?;
+        let flags_of = |name: &str| elf.shentries().get(name).unwrap().flags();
+
+        let kept = flags_of(".text.kept");
+
+        assert!(kept.has(SHFlagBit::GnuRetain));
+        assert!(kept.has(SHFlagBit::ExecInstr));
+        assert!(!flags_of(".tdata").has(SHFlagBit::GnuRetain));
+        assert!(flags_of(".tdata").has(SHFlagBit::TLS));
+
+        /* SHF_EXCLUDE */
+        assert!(flags_of(".discard.me").has(SHFlagBit::Exclude));
+
+        assert_eq!(
+            format!("{:?}", SHFLAGS::from(0x20_0003)),
+            "SHFLAGS([Write, Alloc, OS(2)])"
+        );
+        assert_eq!(
+            format!("{:?}", SHFLAGS::from_osabi(0x8120_0000, 3)),
+            "SHFLAGS([GnuRetain, GnuMbind, Exclude])"
+        );
+
+        /* The high half of an ELF64 `sh_flags` isn't dropped */
+        let mut bytes = std::fs::read("./draft/arr")?;
+        let idx = Elf::parse(&bytes)?
+            .shentries()
+            .iter()
+            .position(|sh| sh.name() == ".comment")
+            .unwrap();
+        let shoff = u64::from_le_bytes(bytes[0x28..0x30].try_into()?);
+        let at = shoff as usize + idx * 64 + 8;
+        let high = (1u64 << 40) | 0x30;
+        bytes[at..at + 8].copy_from_slice(&high.to_le_bytes());
+
+        let patched = Elf::parse(&bytes)?;
+        let flags = patched.shentries().get(".comment").unwrap().flags();
+
+        assert!(flags.has(SHFlagBit::Unknown(1 << 40)));
+        assert!(flags.has(SHFlagBit::StringS));
+        assert_eq!(flags.bits(), high);
+        assert_eq!(patched.to_bytes()?, bytes);
+
+        Ok(())
+    }
+
+    #[test]
+    fn string_tables() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/libarr.so.1")?;
+
+        assert!(elf.dynstr().str_vec().contains(&"libarr.so.1".to_owned()));
+        assert!(elf.strtab().str_vec().contains(&"arr.c".to_owned()));
+        assert!(elf.shstrtab().str_vec().contains(&".dynstr".to_owned()));
+
+        let dynstr_idx = elf.shentries().get(".dynstr").unwrap().name_idx;
+
+        assert_eq!(
+            elf.shstrtab().get(dynstr_idx as usize).unwrap(),
+            ".dynstr"
+        );
+
+        let elf = Elf::load("./draft/arr")?;
+
+        assert!(elf.dynstr().as_bytes().is_empty());
+
+        /* Without the names, the tables are found by the type and link */
+        let mut bytes = std::fs::read("./draft/hello")?;
+        let hello = Elf::parse(&bytes)?;
+        let shoff = hello.ehdr().section_hdr_offset().0 as usize;
+
+        for name in [".symtab", ".strtab", ".dynsym", ".dynstr"] {
+            let at = shoff + hello.shentries().index_of(name).unwrap() * 64;
+            bytes[at..at + 4].copy_from_slice(&0u32.to_le_bytes());
+        }
+
+        let renamed = Elf::parse(&bytes)?;
+
+        assert!(renamed.shentries().get(".symtab").is_none());
+        assert_eq!(renamed.strtab().as_bytes(), hello.strtab().as_bytes());
+        assert_eq!(renamed.dynstr().as_bytes(), hello.dynstr().as_bytes());
+        assert_eq!(renamed.symbol("main"), hello.symbol("main"));
+        assert_eq!(renamed.needed_libraries(), hello.needed_libraries());
+
+        Ok(())
+    }
+
+    #[test]
+    fn dynamic_entries() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/hello")?;
+        let dynamic = elf.dynamic();
+
+        /* Stop at the DT_NULL terminator */
+        assert_eq!(dynamic.len(), 29);
+        assert_eq!(dynamic[0].tag(), DynTag::NEEDED);
+        assert_eq!(dynamic[0].name().as_deref(), Some("libc.so.6"));
+        assert_eq!(dynamic[1].name().as_deref(), Some("/opt/hello/lib"));
+
+        assert_eq!(dynamic[2].tag(), DynTag::INIT);
+        assert_eq!(dynamic[2].val(), 0x1000);
+        assert_eq!(dynamic[2].name(), &None);
+
+        assert_eq!(dynamic[28].tag(), DynTag::RELACOUNT);
+        assert_eq!(dynamic[28].val(), 6);
+
+        let elf = Elf::load("./draft/arr32")?;
+        assert!(elf.dynamic().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn needed_libraries() -> Result<(), Box<dyn Error>> {
+        assert_eq!(
+            Elf::load("./draft/hello")?.needed_libraries(),
+            ["libc.so.6"]
+        );
+
+        for name in ["arr", "arr32", "libarr.so.1"] {
+            let elf = Elf::load(format!("./draft/{}", name))?;
+
+            assert!(elf.needed_libraries().is_empty(), "{}", name);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn soname() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/libarr.so.1")?;
+
+        assert_eq!(elf.soname().as_deref(), Some("libarr.so.1"));
+        assert_eq!(elf.dynamic()[0].tag(), DynTag::SONAME);
+
+        assert_eq!(Elf::load("./draft/hello")?.soname(), None);
+        assert_eq!(Elf::load("./draft/arr")?.soname(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn search_paths() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/libarr.so.1")?;
+
+        assert_eq!(elf.runpath(), ["$ORIGIN/lib", "/opt/arr/lib"]);
+        assert!(elf.rpath().is_empty());
+
+        let elf = Elf::load("./draft/hello")?;
+
+        assert_eq!(elf.rpath(), ["/opt/hello/lib"]);
+        assert!(elf.runpath().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn unmodified_to_bytes() -> Result<(), Box<dyn Error>> {
+        for name in ["arr", "arr32", "libarr.so.1", "hello", "gonote", "flags"]
+        {
+            let bytes = std::fs::read(format!("./draft/{}", name))?;
+
+            assert_eq!(Elf::parse(&bytes)?.to_bytes()?, bytes, "{}", name);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn moved_entry_to_bytes() -> Result<(), Box<dyn Error>> {
+        for name in ["hello", "arr32"] {
+            let bytes = std::fs::read(format!("./draft/{}", name))?;
+            let mut elf = Elf::parse(&bytes)?;
+            elf.set_entry(0x1234);
+
+            let moved = elf.to_bytes()?;
+            assert_eq!(Elf::parse(&moved)?.ehdr().entry().0, 0x1234);
+
+            /* Only the e_entry differs */
+            let diff: Vec<_> =
+                (0..bytes.len()).filter(|&i| moved[i] != bytes[i]).collect();
+            assert!(diff.iter().all(|i| (24..32).contains(i)), "{}", name);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn to_bytes_out_of_range() -> Result<(), Box<dyn Error>> {
+        let mut elf = Elf::load("./draft/arr32")?;
+        elf.set_entry(0x1_0000_0000);
+
+        assert!(matches!(
+            elf.to_bytes(),
+            Err(ElfError::LimitExceeded {
+                what: "e_entry",
+                ..
+            })
+        ));
+
+        /* One more header than the table at the end of the file holds */
+        let mut elf = Elf::load("./draft/arr")?;
+        let last = elf.shentries.0.last().cloned().unwrap();
+        elf.shentries.0.push(last);
+
+        assert!(matches!(
+            elf.to_bytes(),
+            Err(ElfError::Truncated {
+                what: "section header table",
+                ..
+            })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn strip_executable() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/hello")?;
+        let bytes = elf.strip(&StripOptions::default())?;
+        let stripped = Elf::parse(&bytes)?;
+
+        for name in [".symtab", ".strtab", ".comment"] {
+            assert!(stripped.shentries().get(name).is_none(), "{}", name);
+        }
+        assert!(stripped.symtab().is_empty());
+        assert!(bytes.len() < elf.raw().len());
+        assert_eq!(stripped.validate(), Ok(()));
+
+        /* The segments and their contents (but the ELF header) are intact */
+        assert_eq!(stripped.phentries(), elf.phentries());
+        for ph in elf.phentries().iter() {
+            let start = (ph.offset as usize).max(64);
+            let range = start..(ph.offset + ph.filesz) as usize;
+            assert!(bytes.get(range.clone()) == elf.raw().get(range));
+        }
+        assert_eq!(stripped.dynsym(), elf.dynsym());
+        assert_eq!(
+            stripped.section_data_raw(".text"),
+            elf.section_data_raw(".text")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn strip_debug_of_object() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/arrdbg")?;
+        let stripped = Elf::parse(&elf.strip(&StripOptions::default())?)?;
+
+        assert!(stripped.shentries().iter().all(|sh| {
+            !sh.name().contains(".debug_") && sh.name() != ".comment"
+        }));
+
+        /* The .symtab is still needed by the .rela.text */
+        let rela = stripped.shentries().get(".rela.text").unwrap();
+        let symtab = &stripped.shentries().0[*rela.link() as usize];
+        assert_eq!(symtab.name(), ".symtab");
+        assert_eq!(
+            stripped.relocations()[0],
+            elf.relocations()[0],
+            ".rela.text"
+        );
+
+        for (name, section) in [("sum", ".text"), ("arr", ".data")] {
+            let sym = stripped.symbol(name).unwrap();
+            assert_eq!(sym.section_name(&stripped), Some(section));
+        }
+        assert_eq!(
+            stripped.section_data_raw(".data"),
+            elf.section_data_raw(".data")
+        );
+
+        let kept = elf.strip(&StripOptions {
+            comment: false,
+            ..Default::default()
+        })?;
+        assert!(Elf::parse(&kept)?.shentries().get(".comment").is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn views_equality() -> Result<(), Box<dyn Error>> {
+        let bytes = std::fs::read("./draft/libarr.so.1")?;
+        let (mapped, parsed) =
+            (Elf::load("./draft/libarr.so.1")?, Elf::parse(&bytes)?);
+
+        assert_eq!(mapped.ehdr(), parsed.ehdr());
+        assert_eq!(mapped.phentries(), parsed.phentries());
+        assert_eq!(mapped.shentries(), parsed.shentries());
+        assert_eq!(mapped.dynsym(), parsed.dynsym());
+        assert_eq!(mapped.dynamic(), parsed.dynamic());
+        assert_eq!(mapped.verdef(), parsed.verdef());
+        assert_eq!(mapped.gnu_hash(), parsed.gnu_hash());
+
+        /* Dedup the symbols of both tables */
+        let mut syms: Vec<_> = mapped
+            .symtab()
+            .iter()
+            .chain(mapped.dynsym().iter())
+            .filter(|sym| sym.name() == "sum")
+            .map(|sym| SymView {
+                name_idx: 0,
+                strtab_idx: 0,
+                version: None,
+                ..sym.clone()
+            })
+            .collect();
+        syms.dedup();
+
+        assert_eq!(syms.len(), 1);
+
+        let mut patched = bytes.clone();
+        patched[7] = 3;
+        let patched = Elf::parse(&patched)?;
+
+        assert_ne!(mapped.ehdr(), patched.ehdr());
+        assert_eq!(mapped.ehdr().machine(), patched.ehdr().machine());
+
+        Ok(())
+    }
+
+    #[test]
+    fn load_from_reader() -> Result<(), Box<dyn Error>> {
+        let bytes = std::fs::read("./draft/hello")?;
+
+        /* Read in two pieces, as a stream */
+        let (head, tail) = bytes.split_at(100);
+        let elf = Elf::from_reader(head.chain(tail))?;
+
+        assert_eq!(elf.to_bytes()?, bytes);
+        assert_eq!(elf.build_id(), Elf::parse(&bytes)?.build_id());
+
+        assert!(matches!(
+            Elf::from_reader(&bytes[..10]),
+            Err(ElfError::Truncated { .. })
+        ));
+
+        /* The error of the stream itself */
+        struct Broken;
+
+        impl Read for Broken {
+            fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+                Err(std::io::ErrorKind::BrokenPipe.into())
+            }
+        }
+
+        assert!(matches!(Elf::from_reader(Broken), Err(ElfError::Io(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn remove_section_fixes_references() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/hello")?;
+
+        let linked_names = |elf: &Elf| {
+            elf.shentries()
+                .0
+                .iter()
+                .map(|sh| {
+                    let name_at = |idx: u32| {
+                        elf.shentries().0[idx as usize].name().clone()
+                    };
+                    let info = if sh.info_is_section_index() {
+                        name_at(sh.info)
+                    } else {
+                        sh.info.to_string()
+                    };
+
+                    (sh.name().clone(), name_at(sh.link), info)
+                })
+                .collect::<Vec<_>>()
+        };
+        let before = linked_names(&elf);
+
+        /* Strip the notes before all the linked sections */
+        let mut stripped = elf.clone();
+        stripped.remove_section(".note.gnu.property")?;
+        stripped.remove_section(".note.gnu.build-id")?;
+        stripped.remove_section(".note.ABI-tag")?;
+
+        let stripped = Elf::parse(&stripped.to_bytes()?)?;
+        let after = linked_names(&stripped);
+
+        assert_eq!(stripped.shentries().0.len(), before.len() - 3);
+        assert_eq!(
+            after,
+            before
+                .into_iter()
+                .filter(|(name, ..)| !name.starts_with(".note"))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(stripped.validate(), Ok(()));
+        assert_eq!(stripped.dynsym().0.len(), elf.dynsym().0.len());
+
+        /* The linked string table is removed */
+        let mut stripped = elf.clone();
+        stripped.remove_section(".strtab")?;
+
+        assert_eq!(stripped.shentries().get(".symtab").unwrap().link, 0);
+
+        /* The symbols follow their shifted sections */
+        let mut stripped = elf.clone();
+        stripped.remove_section(".interp")?;
+
+        let main = stripped.symbol("main").unwrap();
+        assert_eq!(main.section_name(&stripped), Some(".text"));
+
+        let stripped = Elf::parse(&stripped.to_bytes()?)?;
+        let main = stripped.symbol("main").unwrap();
+        assert_eq!(main.section_name(&stripped), Some(".text"));
+
+        /* Neither the null section nor the section names */
+        let mut elf = Elf::load("./draft/arr")?;
+        for name in ["", ".shstrtab"] {
+            assert!(matches!(
+                elf.remove_section(name),
+                Err(ElfError::SectionRequired(_))
+            ));
+        }
+        assert_eq!(elf.to_bytes()?, std::fs::read("./draft/arr")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn not_elf() {
+        let detected = |bytes: &[u8]| Elf::parse(bytes).err();
+
+        /* DOS stub header of a PE file */
+        let mut pe = b"MZ\x90\x00\x03\x00\x00\x00\x04\x00".to_vec();
+        pe.resize(64, 0);
+
+        assert!(matches!(
+            detected(&pe),
+            Some(ElfError::NotElf {
+                detected: Format::Pe
+            })
+        ));
+        assert!(matches!(
+            detected(&[0xcf, 0xfa, 0xed, 0xfe, 7, 0, 0, 1]),
+            Some(ElfError::NotElf {
+                detected: Format::MachO64
+            })
+        ));
+        assert!(matches!(detected(b"#!/bin/sh"), Some(ElfError::BadMagic)));
+        assert!(matches!(detected(b""), Some(ElfError::BadMagic)));
+        assert!(matches!(
+            detected(b"\x7fELF\x02"),
+            Some(ElfError::Truncated {
+                what: "identification",
+                ..
+            })
+        ));
+
+        let mut bad_class = b"\x7fELF\x03\x01\x01".to_vec();
+        bad_class.resize(64, 0);
+        assert!(matches!(detected(&bad_class), Some(ElfError::Malformed(_))));
+    }
+
+    #[test]
+    fn symbol_at_section_offset() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/arr")?;
+        let name_at = |section: &str, offset: u64| {
+            elf.symbol_at_section_offset(section, offset)
+                .map(|sym| sym.name().as_str())
+        };
+
+        assert_eq!(name_at(".text", 0), Some("square"));
+        assert_eq!(name_at(".text", 0xe), Some("square"));
+        assert_eq!(name_at(".text", 0xf), Some("sum"));
+        assert_eq!(name_at(".text", 0x7d), Some("sum_v0"));
+        assert_eq!(name_at(".text", 0x89), None);
+        assert_eq!(name_at(".data", 39), Some("arr"));
+        assert_eq!(name_at(".data", 40), None);
+        assert_eq!(name_at(".nope", 0), None);
+
+        /* Not for the symbol values of addresses */
+        let elf = Elf::load("./draft/hello")?;
+
+        assert!(elf.symbol_at_section_offset(".text", 0).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn constructors_and_destructors() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/hello")?;
+        let addr_of = |name: &str| {
+            let sym = elf.symtab().0.iter().find(|sym| sym.name() == name);
+
+            sym.unwrap().value().as_signed() as u64
+        };
+
+        assert_eq!(
+            elf.constructors(),
+            [
+                addr_of("early"),
+                addr_of("_init"),
+                addr_of("frame_dummy"),
+                addr_of("init_first")
+            ]
+        );
+        assert_eq!(
+            elf.destructors(),
+            [
+                addr_of("fini_last"),
+                addr_of("__do_global_dtors_aux"),
+                addr_of("_fini")
+            ]
+        );
+
+        assert!(Elf::load("./draft/arr")?.constructors().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn fuzz_corpus_never_panics() -> Result<(), Box<dyn Error>> {
+        /* Load and poke around, any panic fails the test */
+        let exercise = |bytes: &[u8]| {
+            let Ok(mut elf) = Elf::parse(bytes) else {
+                return false;
+            };
+
+            let _ = elf.validate();
+            let _ = elf.as_readelf_header();
+            let _ = elf.versioned_dynsyms();
+            let _ = (elf.go_build_id(), elf.soname(), elf.runpath());
+            let _ = (elf.constructors(), elf.destructors());
+            let _ = (elf.overlay(), elf.to_bytes(), elf.entry_is_executable());
+
+            for sh in elf.shentries().0.iter() {
+                let _ = sh.interpret_link_info(&elf);
+                let _ = elf.symbol_at_section_offset(sh.name(), 0);
+            }
+
+            if let Some(name) = elf.shentries().0.last().map(|sh| sh.name()) {
+                let _ = elf.clone().remove_section(&name.clone());
+                let _ = elf.rename_section(&name.clone(), ".renamed");
+            }
+
+            true
+        };
+
+        /* Loaded on purpose, only a lenient part of them is broken, any
+         * other file of the corpus has to be rejected */
+        const ACCEPTED: [&str; 12] = [
+            /* An invalid EI_DATA is read as little endian */
+            "bad_data",
+            /* The 64-bit tables, misread as 32-bit ones, fit the file */
+            "class_32",
+            /* The links are followed on demand only */
+            "cyclic_links",
+            /* The entries are read up to the end of file */
+            "dynamic_size_huge",
+            /* A broken hash table is left out */
+            "gnu_hash_buckets_huge",
+            /* PN_XNUM, the real count in the section 0 is zero */
+            "huge_phnum",
+            /* The unknown values are kept as they are */
+            "out_of_range_enums",
+            /* A missing name (or string table) is left empty */
+            "sh_name_out_of_range",
+            "shstrndx_out_of_range",
+            "strtab_out_of_range",
+            "xindex_without_sections",
+            /* The version walk stops at the end of the section */
+            "verdef_count_huge",
+        ];
+        let mut loaded = vec![];
+
+        for entry in std::fs::read_dir("./tests/fuzz_corpus")? {
+            let path = entry?.path();
+
+            if path.extension().is_some() {
+                continue;
+            }
+
+            let name =
+                path.file_name().unwrap().to_string_lossy().into_owned();
+
+            assert_eq!(
+                exercise(&std::fs::read(&path)?),
+                ACCEPTED.contains(&name.as_str()),
+                "{}",
+                name
+            );
+
+            if ACCEPTED.contains(&name.as_str()) {
+                loaded.push(name);
+            }
+        }
+
+        assert_eq!(loaded.len(), ACCEPTED.len());
+
+        /* Truncated everywhere */
+        let arr = std::fs::read("./draft/arr")?;
+
+        for len in 0..arr.len() {
+            assert!(!exercise(&arr[..len]), "truncated at {}", len);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn truncated_prefixes() -> Result<(), Box<dyn Error>> {
+        let mut seed = 0x2545_f491_u64;
+
+        for name in ["hello", "arr32", "libarr.so.1"] {
+            let bytes = std::fs::read(format!("./draft/{}", name))?;
+
+            for _ in 0..200 {
+                /* xorshift, a random length below the full one */
+                seed ^= seed << 13;
+                seed ^= seed >> 7;
+                seed ^= seed << 17;
+                let len = 4 + seed as usize % (bytes.len() - 4);
+
+                let err = Elf::parse(&bytes[..len]).unwrap_err();
+
+                assert!(
+                    matches!(err, ElfError::Truncated { .. }),
+                    "{} at {}: {}",
+                    name,
+                    len,
+                    err
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn raw_symbol_bytes() -> Result<(), Box<dyn Error>> {
+        for name in ["arr", "libarr.so.1", "hello"] {
+            let elf = Elf::load(format!("./draft/{}", name))?;
+
+            for (sh_name, symtab) in
+                [(".symtab", elf.symtab()), (".dynsym", elf.dynsym())]
+            {
+                let Some(sh) = elf.shentries().get(sh_name) else {
+                    continue;
+                };
+
+                for (i, sym) in symtab.0.iter().enumerate() {
+                    let raw: E64Sym = deserialize_nth(
+                        elf.raw(),
+                        sh.offset.0 as usize,
+                        i,
+                        EIData::LSB,
+                    )
+                    .unwrap();
+
+                    assert_eq!(sym.raw_info(), raw.info(), "{}", sym.name);
+                    assert_eq!(sym.raw_other(), raw.other(), "{}", sym.name);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn iterate_sections() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/hello")?;
+        let shentries = elf.shentries();
+
+        let mut names = vec![];
+        for sh in shentries {
+            names.push(sh.name().as_str());
+        }
+
+        assert!(names.contains(&".text"));
+        assert_eq!(names.len(), shentries.len());
+        assert_eq!(names[0], "");
+        assert!(!shentries.is_empty());
+
+        let text = shentries.index_of(".text").unwrap();
+        assert_eq!(shentries.get_index(text).unwrap().name(), ".text");
+        assert!(shentries.get_index(shentries.len()).is_none());
+
+        assert!(Elf::parse(&synth_core())?.shentries().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn sections_by_type() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/hello")?;
+        let names_of = |ty: SHType| {
+            elf.shentries()
+                .find_by_type(ty)
+                .map(|sh| sh.name().as_str())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(names_of(SHType::RELA), [".rela.dyn", ".rela.plt"]);
+        assert_eq!(names_of(SHType::SYMtab), [".symtab"]);
+        assert_eq!(names_of(SHType::SPECOS(0x6fff_ffff)), [".gnu.version"]);
+        assert!(names_of(SHType::SPECPROC(0x7000_0001)).is_empty());
+
+        /* By the raw value, either of the GNU hash forms */
+        for ty in [SHType::GNUHASH, SHType::SPECOS(0x6fff_fff6)] {
+            let sh = elf.shentries().first_by_type(ty).unwrap();
+            assert_eq!(sh.name(), ".gnu.hash");
+        }
+
+        assert!(elf.shentries().first_by_type(SHType::GROUP).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn sections_by_flags() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/hello")?;
+        let names_of = |secs: Vec<&SHdrView>| -> Vec<String> {
+            secs.into_iter().map(|sh| sh.name().clone()).collect()
+        };
+
+        assert_eq!(
+            names_of(elf.sections_with_flags(&[SHFlagBit::ExecInstr])),
+            [".init", ".plt", ".plt.got", ".text", ".fini"]
+        );
+        assert_eq!(
+            names_of(
+                elf.sections_with_flags(&[
+                    SHFlagBit::Merge,
+                    SHFlagBit::StringS
+                ])
+            ),
+            [".comment"]
+        );
+        assert_eq!(elf.sections_with_flags(&[]).len(), elf.shentries().len());
+        assert!(elf.writable_executable_sections().is_empty());
+
+        /* sh_flags of .data as WAX */
+        let data = elf.shentries().index_of(".data").unwrap();
+        let shoff = elf.ehdr().section_hdr_offset().0 as usize;
+        let mut bytes = elf.to_bytes()?;
+        bytes[shoff + data * 64 + 8] = 0x7;
+
+        let wx = Elf::parse(&bytes)?;
+        assert_eq!(names_of(wx.writable_executable_sections()), [".data"]);
+
+        let sh = wx.shentries().get(".data").unwrap();
+        assert!(sh.has_flag(SHFlagBit::Alloc));
+        assert!(!sh.has_flag(SHFlagBit::TLS));
+        assert!(!sh.has_flag(SHFlagBit::OS(1)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn iterate_symbols() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/arr")?;
+        let symtab = elf.symtab();
+
+        assert!(!symtab.is_empty());
+        assert_eq!(symtab.iter().count(), symtab.len());
+        assert_eq!(symtab[0].name(), "");
+        assert!(symtab.get(symtab.len()).is_none());
+
+        let mut funcs = vec![];
+        for sym in symtab {
+            if *sym.ty() == SymType::Func {
+                funcs.push(sym.name().as_str());
+            }
+        }
+
+        let filtered: Vec<&str> = symtab
+            .filter_by_type(SymType::Func)
+            .map(|sym| sym.name().as_str())
+            .collect();
+
+        assert_eq!(filtered, funcs);
+        for func in ["square", "sum", "inc", "sum_v0"] {
+            assert!(funcs.contains(&func), "{func} not in {funcs:?}");
+        }
+
+        assert!(symtab
+            .filter_by_type(SymType::Object)
+            .any(|sym| sym.name() == "arr"));
+        assert!(Elf::load("./draft/arr")?.dynsym().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn symbol_by_name() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/hello")?;
+
+        let main = elf.symbol("main").unwrap();
+        assert!(matches!(main.value(), SymValue::VirAddr(Hex64(0x1195))));
+        assert_eq!(*main.size(), 114);
+
+        /* Only the dynamic one is undecorated by the version */
+        let printf = elf.symbol("__printf_chk").unwrap();
+        assert!(matches!(printf.shndx(), SID::Undef));
+        assert!(matches!(printf.value(), SymValue::Undefined));
+
+        assert!(elf.symbol("nope").is_none());
+        assert!(elf.symbol("").is_none());
+
+        let lib = Elf::load("./draft/libarr.so.1")?;
+        let sums = lib.symbols_by_name("sum");
+
+        assert_eq!(sums.len(), 2);
+        assert!(sums.iter().all(|sym| *sym.size() == 76));
+
+        Ok(())
+    }
+
+    #[test]
+    fn gnu_hash_lookup() -> Result<(), Box<dyn Error>> {
+        assert_eq!(GnuHashTable::hash(b""), 5381);
+        assert_eq!(GnuHashTable::hash(b"printf"), 0x156b_2bb8);
+
+        for name in ["libarr.so.1", "hello"] {
+            let elf = Elf::load(format!("./draft/{}", name))?;
+            let table = elf.gnu_hash().as_ref().unwrap();
+            let scan = |name: &str| {
+                elf.dynsym().iter().find(|sym| {
+                    sym.name() == name && !matches!(sym.shndx(), SID::Undef)
+                })
+            };
+
+            for sym in elf.dynsym().iter().skip(1) {
+                assert_eq!(
+                    elf.dynamic_symbol_lookup(sym.name())
+                        .map(|s| s as *const _),
+                    scan(sym.name()).map(|s| s as *const _),
+                    "{}: {}",
+                    name,
+                    sym.name()
+                );
+            }
+
+            assert!(table.symoffset() > 0);
+            assert!(elf.dynamic_symbol_lookup("no_such_symbol").is_none());
+        }
+
+        let lib = Elf::load("./draft/libarr.so.1")?;
+        assert_eq!(lib.dynamic_symbol_lookup("sum").unwrap().name(), "sum");
+
+        /* The linear scan without the hash table */
+        let arr = Elf::load("./draft/arr")?;
+        assert!(arr.gnu_hash().is_none());
+        assert!(arr.dynamic_symbol_lookup("sum").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn relro_status() -> Result<(), Box<dyn Error>> {
+        /* -z relro -z now */
+        let hello = Elf::load("./draft/hello")?;
+        assert!(hello
+            .phentries()
+            .iter()
+            .any(|ph| matches!(ph.ty(), PhType::GNURELRO)));
+        assert_eq!(hello.relro(), RelroStatus::Full);
+
+        /* The lazy binding of the default */
+        assert_eq!(
+            Elf::load("./draft/libtls.so.1")?.relro(),
+            RelroStatus::Partial
+        );
+
+        /* No segments at all */
+        assert_eq!(Elf::load("./draft/arr")?.relro(), RelroStatus::None);
+
+        assert_eq!(u32::from(PhType::GNURELRO), 0x6474_e552);
+        assert_eq!(PhType::from(0x6474_e552), PhType::GNURELRO);
+
+        Ok(())
+    }
+
+    #[test]
+    fn nx_stack() -> Result<(), Box<dyn Error>> {
+        let hello = Elf::load("./draft/hello")?;
+        assert!(hello.nx_enabled());
+
+        let stack = hello
+            .phentries()
+            .iter()
+            .position(|ph| matches!(ph.ty(), PhType::GNUSTACK))
+            .unwrap();
+        assert_eq!(u32::from(hello.phentries()[stack].ty), 0x6474_e551);
+
+        /* p_flags of -z execstack */
+        let mut buf = hello.raw().to_vec();
+        let ph = 64 + 56 * stack;
+        buf[ph + 4..ph + 8].copy_from_slice(&7u32.to_le_bytes());
+        assert!(!Elf::parse(&buf)?.nx_enabled());
+
+        /* No PT_GNU_STACK */
+        buf[ph..ph + 4].copy_from_slice(&0u32.to_le_bytes());
+        assert!(!Elf::parse(&buf)?.nx_enabled());
+
+        assert!(!Elf::load("./draft/arr")?.nx_enabled());
+
+        assert_eq!(PhType::from(0x6474_e550), PhType::GNUEHFRAME);
+        assert!(hello
+            .phentries()
+            .iter()
+            .any(|ph| matches!(ph.ty(), PhType::GNUEHFRAME)));
+        assert_eq!(PhType::from(0x6474_e553), PhType::SPECOS(0x6474_e553));
+
+        Ok(())
+    }
+
+    #[test]
+    fn canary_and_fortify() -> Result<(), Box<dyn Error>> {
+        /* -D_FORTIFY_SOURCE=2 -fstack-protector-all */
+        let hello = Elf::load("./draft/hello")?;
+        assert!(hello.has_stack_canary());
+        assert!(hello.uses_fortify());
+
+        /* The versioned names of .strtab alone */
+        assert!(hello
+            .symtab()
+            .iter()
+            .any(|sym| sym.name() == "__stack_chk_fail@GLIBC_2.4"));
+        let mut buf = hello.raw().to_vec();
+        let dynsym = hello.shentries().get(".dynsym").unwrap();
+        buf[dynsym.offset.0 as usize..][..dynsym.size as usize].fill(0);
+        let elf = Elf::parse(&buf)?;
+        assert!(elf.dynsym().iter().all(|sym| sym.name().is_empty()));
+        assert!(elf.has_stack_canary());
+        assert!(elf.uses_fortify());
+
+        /* Resolved the same without the names */
+        let lazy = Elf::parse_lazy(hello.raw())?;
+        assert!(lazy.has_stack_canary());
+        assert!(lazy.uses_fortify());
+
+        let lib = Elf::load("./draft/libarr.so.1")?;
+        assert!(!lib.has_stack_canary());
+        assert!(!lib.uses_fortify());
+
+        Ok(())
+    }
+
+    #[test]
+    fn code_fingerprint() -> Result<(), Box<dyn Error>> {
+        let hello = Elf::load("./draft/hello")?;
+        let fingerprint = hello.code_fingerprint();
+        assert_eq!(fingerprint, hello.code_fingerprint());
+
+        let patch = |name: &str, byte: u8| {
+            let sh = hello.shentries().get(name).unwrap();
+            let mut buf = hello.raw().to_vec();
+            buf[sh.offset.0 as usize] ^= byte;
+
+            Elf::parse(&buf)
+        };
+
+        /* Neither the comment nor the build ID counts */
+        assert_eq!(patch(".comment", 0xff)?.code_fingerprint(), fingerprint);
+        assert_eq!(
+            patch(".note.gnu.build-id", 0xff)?.code_fingerprint(),
+            fingerprint
+        );
+        assert_eq!(patch(".rodata", 0xff)?.code_fingerprint(), fingerprint);
+
+        /* Each executable section does */
+        for name in [".init", ".plt", ".text", ".fini"] {
+            assert_ne!(
+                patch(name, 0xff)?.code_fingerprint(),
+                fingerprint,
+                "{}",
+                name
+            );
+        }
+
+        /* The empty .text alone, a name, a NUL and the size */
+        let mut stream = b".text\0".to_vec();
+        stream.extend(0u64.to_le_bytes());
+        assert_eq!(
+            Elf::load("./draft/gonote")?.code_fingerprint(),
+            <[u8; 32]>::from(Sha256::digest(&stream))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn hex64_parse_and_display() -> Result<(), Box<dyn Error>> {
+        assert_eq!("0x401000".parse::<Hex64>()?, Hex64::new(0x40_1000));
+        assert_eq!("0X401000".parse::<Hex64>()?, Hex64(0x40_1000));
+        assert_eq!(Hex64::try_from("deadBEEF")?.value(), 0xdead_beef);
+        assert_eq!(Hex64::try_from("0xffffffffffffffff")?, Hex64(u64::MAX));
+
+        assert!("".parse::<Hex64>().is_err());
+        assert!("0x".parse::<Hex64>().is_err());
+        assert!("0x10000000000000000".parse::<Hex64>().is_err());
+        assert!("4096z".parse::<Hex64>().is_err());
+
+        let entry = *Elf::load("./draft/hello")?.ehdr().entry();
+        assert_eq!(entry.to_string(), "0x0000000000001070");
+        assert_eq!(entry.to_string(), format!("{:?}", entry));
+        assert_eq!(entry.to_string().parse::<Hex64>()?, entry);
+
+        /* The width of the format is that of the digits */
+        assert_eq!(format!("{:8?}", entry), "0x00001070");
+        assert_eq!(format!("{:4}", Hex64(0x40_1000)), "0x401000");
+        assert_eq!(format!("{:?}", Hex64(u64::MAX)), "0xffffffffffffffff");
+
+        Ok(())
+    }
+
+    #[test]
+    fn sections_with_data() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/hello")?;
+        let raw = elf.raw();
+
+        assert_eq!(elf.sections_with_data().count(), elf.shentries().len());
+
+        /* The headers and the section contents cover the file but the
+         * alignment padding */
+        let ehdr = elf.ehdr();
+        let mut covered = vec![false; raw.len()];
+        let mut cover = |off: usize, len: usize| {
+            covered[off..off + len].iter_mut().for_each(|b| *b = true)
+        };
+        cover(0, 64);
+        cover(
+            ehdr.prog_hdr_offset().0 as usize,
+            56 * *ehdr.prog_hdr_tab_ent_num() as usize,
+        );
+        cover(
+            ehdr.section_hdr_offset.0 as usize,
+            64 * elf.shentries().len(),
+        );
+
+        let mut total = 0;
+
+        for (sh, data) in elf.sections_with_data() {
+            match data {
+                Some(data) => {
+                    assert_eq!(data.len() as u64, sh.size);
+                    assert_eq!(Some(data), elf.section_data_raw(&sh.name));
+                    total += data.len();
+                    cover(sh.offset.0 as usize, data.len());
+                }
+                None => assert!(matches!(sh.ty, SHType::NOBITS)),
+            }
+        }
+
+        let gaps = raw
+            .iter()
+            .zip(covered.iter())
+            .filter(|(_, &covered)| !covered)
+            .map(|(&b, _)| b)
+            .collect::<Vec<_>>();
+        assert!(gaps.iter().all(|&b| b == 0));
+
+        /* No section overlaps another in the file */
+        let headers = 64
+            + 56 * *ehdr.prog_hdr_tab_ent_num() as usize
+            + 64 * elf.shentries().len();
+        assert_eq!(total + headers + gaps.len(), raw.len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn init_fini_functions() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/hello")?;
+        let names = |addrs: Vec<u64>| {
+            addrs
+                .into_iter()
+                .map(|addr| elf.symbol_at_addr(addr).unwrap().name().clone())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            names(elf.init_functions()),
+            ["early", "frame_dummy", "init_first"]
+        );
+        assert_eq!(
+            names(elf.fini_functions()),
+            ["fini_last", "__do_global_dtors_aux"]
+        );
+
+        /* No DT_INIT_ARRAY of the dynamic section, as a static one */
+        let dynamic = elf.shentries().get(".dynamic").unwrap();
+        let mut buf = elf.raw().to_vec();
+        buf[dynamic.offset.0 as usize..][..dynamic.size as usize].fill(0);
+        let stat = Elf::parse(&buf)?;
+        assert!(stat.constructors().is_empty());
+        assert_eq!(stat.init_functions(), elf.init_functions());
+        assert_eq!(stat.fini_functions(), elf.fini_functions());
+
+        let lib = Elf::load("./draft/libarr.so.1")?;
+        let init = lib.init_functions();
+        assert_eq!(init.len(), 1);
+        assert_eq!(lib.symbol_at_addr(init[0]).unwrap().name(), "frame_dummy");
+
+        /* Named, in the lazy mode too, and none of a stripped file */
+        let lazy = Elf::parse_lazy(elf.raw())?;
+        let fini = lazy.fini_functions_named();
+        assert_eq!(lazy.init_functions_named(), elf.init_functions_named());
+        assert_eq!(fini[0], (elf.fini_functions()[0], Some("fini_last")));
+
+        let stripped = Elf::parse(&elf.strip(&StripOptions::default())?)?;
+        let init = stripped.init_functions_named();
+        assert!(stripped.symtab().is_empty());
+        assert_eq!(init.len(), 3);
+        assert!(init.iter().all(|(_, name)| name.is_none()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn gnu_property_note() -> Result<(), Box<dyn Error>> {
+        /* -fcf-protection=full */
+        let cet = Elf::load("./draft/arrcet")?;
+        let props = cet.gnu_properties().unwrap();
+        assert!(props.ibt());
+        assert!(props.shadow_stack());
+        assert!(!props.bti() && !props.pac());
+
+        /* The ISA property alone, the CRT objects have no CET */
+        let hello = Elf::load("./draft/hello")?;
+        assert_eq!(hello.gnu_properties(), Some(GnuProperties::default()));
+        assert_eq!(Elf::load("./draft/arr")?.gnu_properties(), None);
+
+        /* The same bits as GNU_PROPERTY_AARCH64_FEATURE_1_AND of AArch64 */
+        let sh = cet.shentries().get(".note.gnu.property").unwrap();
+        let desc = sh.offset.0 as usize + 16;
+        let mut buf = cet.raw().to_vec();
+        assert_eq!(buf[desc..desc + 4], 0xc000_0002u32.to_le_bytes());
+        buf[desc..desc + 4].copy_from_slice(&0xc000_0000u32.to_le_bytes());
+        buf[18..20].copy_from_slice(&183u16.to_le_bytes());
+
+        let props = Elf::parse(&buf)?.gnu_properties().unwrap();
+        assert!(props.bti() && props.pac());
+        assert!(!props.ibt() && !props.shadow_stack());
+
+        /* The x86 type means another property of AArch64 */
+        buf[desc..desc + 4].copy_from_slice(&0xc000_0002u32.to_le_bytes());
+        assert_eq!(
+            Elf::parse(&buf)?.gnu_properties(),
+            Some(GnuProperties::default())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn sysv_hash_lookup() -> Result<(), Box<dyn Error>> {
+        assert_eq!(SysvHashTable::hash(b""), 0);
+        assert_eq!(SysvHashTable::hash(b"printf"), 0x0779_05a6);
+
+        /* Past 7 chars, the high nibble is folded back */
+        assert_eq!(
+            SysvHashTable::hash(b"__libc_start_main"),
+            b"__libc_start_main".iter().fold(0u32, |h, &c| {
+                let h = (h << 4) + c as u32;
+                let g = h & 0xf000_0000;
+                (if g != 0 { h ^ (g >> 24) } else { h }) & !g
+            })
+        );
+
+        let elf = Elf::load("./draft/libsysv.so.1")?;
+        assert!(elf.gnu_hash().is_none());
+
+        let table = elf.sysv_hash().as_ref().unwrap();
+        assert_eq!(table.chain().len(), elf.dynsym().len());
+
+        let scan = |name: &str| {
+            elf.dynsym().iter().find(|sym| {
+                sym.name() == name && !matches!(sym.shndx(), SID::Undef)
+            })
+        };
+
+        for sym in elf.dynsym().iter().skip(1) {
+            assert_eq!(
+                elf.sysv_hash_lookup(sym.name()).map(|s| s as *const _),
+                scan(sym.name()).map(|s| s as *const _),
+                "{}",
+                sym.name()
+            );
+        }
+
+        assert_eq!(elf.sysv_hash_lookup("sum").unwrap().name(), "sum");
+        assert_eq!(elf.dynamic_symbol_lookup("inc"), scan("inc"));
+        assert!(elf.sysv_hash_lookup("__gmon_start__").is_none());
+        assert!(elf.sysv_hash_lookup("no_such_symbol").is_none());
+
+        /* Only the GNU table in the others */
+        assert!(Elf::load("./draft/hello")?.sysv_hash().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn symbol_at_addr() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/hello")?;
+        let name_at = |addr| elf.symbol_at_addr(addr).map(|sym| sym.name());
+
+        assert_eq!(name_at(0x1195).unwrap(), "main");
+        assert_eq!(name_at(0x1195 + 113).unwrap(), "main");
+        assert_eq!(name_at(0x1207).unwrap(), "init_first");
+        assert_eq!(name_at(0x4052).unwrap(), "counter");
+
+        /* Zero-size frame_dummy, up to the next symbol */
+        assert_eq!(name_at(0x1158).unwrap(), "frame_dummy");
+        assert_eq!(name_at(0x1159).unwrap(), "early");
+
+        assert_eq!(name_at(0x10), None);
+
+        let arr = Elf::load("./draft/arr")?;
+        assert!(arr.symbol_at_addr(0x10).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn symbols_in_section() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/arr")?;
+
+        let names: Vec<&str> = elf
+            .symbols_in_section(".text")
+            .into_iter()
+            .map(|sym| sym.name().as_str())
+            .filter(|name| !name.is_empty())
+            .collect();
+
+        for func in ["square", "sum", "inc", "sum_v0"] {
+            assert!(names.contains(&func), "{func} not in {names:?}");
+        }
+        assert!(!names.contains(&"arr"));
+
+        for sym in elf.symbols_in_section(".data") {
+            assert_eq!(sym.section_name(&elf), Some(".data"));
+        }
+
+        let lib = Elf::load("./draft/libarr.so.1")?;
+        assert!(lib
+            .symbols_in_section(".text")
+            .iter()
+            .any(|sym| sym.name() == "sum"));
+
+        assert!(elf.symbols_in_section(".nope").is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn versioned_dynsyms() -> Result<(), Box<dyn Error>> {
+        let lib = Elf::load("./draft/libarr.so.1")?;
+        let find = |elf: &Elf, name: &str| {
+            elf.versioned_dynsyms()
+                .into_iter()
+                .find(|(sym, _)| sym.name() == name)
+                .and_then(|(_, ver)| ver)
+        };
+
+        let sum = find(&lib, "sum").unwrap();
+        assert_eq!(sum.name(), "ARR_1.0");
+        assert!(sum.is_default() && !sum.hidden());
+
+        let sum_v0 = find(&lib, "sum_v0").unwrap();
+        assert_eq!(sum_v0.name(), "ARR_0.9");
+        assert!(!sum_v0.is_default() && sum_v0.hidden());
+
+        assert_eq!(lib.verdef()[2].parents(), &["ARR_0.9"]);
+
+        let hello = Elf::load("./draft/hello")?;
+        let printf = find(&hello, "__printf_chk").unwrap();
+        assert_eq!(printf.name(), "GLIBC_2.3.4");
+        assert!(!printf.is_default() && !printf.hidden());
+
+        assert_eq!(find(&hello, "__gmon_start__"), None);
+
+        /* The same names are attached to the symbols */
+        let version_of = |elf: &Elf, name: &str| {
+            elf.dynsym()
+                .iter()
+                .find(|sym| sym.name() == name)
+                .and_then(|sym| sym.version().clone())
+        };
+
+        assert_eq!(version_of(&lib, "sum_v0").as_deref(), Some("ARR_0.9"));
+        assert_eq!(
+            version_of(&hello, "__printf_chk").as_deref(),
+            Some("GLIBC_2.3.4")
+        );
+        assert_eq!(version_of(&hello, "__gmon_start__"), None);
+        assert!(hello.symtab().iter().all(|sym| sym.version().is_none()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn imports_by_library() -> Result<(), Box<dyn Error>> {
+        let hello = Elf::load("./draft/hello")?;
+        let imports = hello.imports_by_library();
+
+        let libc = &imports["libc.so.6"];
+        assert!(libc.contains(&(
+            "__printf_chk".to_owned(),
+            Some("GLIBC_2.3.4".to_owned())
+        )));
+        assert!(libc
+            .iter()
+            .all(|(name, ver)| ver.is_some() && !name.is_empty()));
+
+        let unversioned = &imports[""];
+        assert!(unversioned.contains(&("__gmon_start__".to_owned(), None)));
+
+        /* No dynamic section */
+        let arr = Elf::load("./draft/arr")?;
+        assert!(arr.imports_by_library().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn is_valid_elf() -> Result<(), Box<dyn Error>> {
+        for name in ["arr", "libarr.so.1", "hello", "gonote", "flags"] {
+            let bytes = std::fs::read(format!("./draft/{}", name))?;
+            assert!(Elf::is_valid_elf(&bytes), "{}", name);
+        }
+
+        let bytes = std::fs::read("./draft/hello")?;
+        let patched = |off: usize, val: &[u8]| {
+            let mut bytes = bytes.clone();
+            bytes[off..off + val.len()].copy_from_slice(val);
+            bytes
+        };
+
+        assert!(!Elf::is_valid_elf(&[]));
+        assert!(!Elf::is_valid_elf(b"\x7fELF"));
+        assert!(!Elf::is_valid_elf(&bytes[..63]));
+        assert!(!Elf::is_valid_elf(&bytes[..bytes.len() - 1]));
+        assert!(!Elf::is_valid_elf(&patched(0, b"MZ")));
+        assert!(!Elf::is_valid_elf(&patched(4, &[3])));
+        assert!(!Elf::is_valid_elf(&patched(5, &[0])));
+        assert!(!Elf::is_valid_elf(&patched(6, &[2])));
+        assert!(!Elf::is_valid_elf(&patched(32, &u64::MAX.to_le_bytes())));
+        assert!(!Elf::is_valid_elf(&patched(58, &[8, 0])));
+        assert!(!Elf::is_valid_elf(&patched(60, &[0xff, 0xff])));
+
+        /* A 32-bit header alone, without tables */
+        let mut elf32 = vec![0; 52];
+        elf32[..7].copy_from_slice(b"\x7fELF\x01\x01\x01");
+        elf32[40] = 52;
+        assert!(Elf::is_valid_elf(&elf32));
+        assert!(!Elf::is_valid_elf(&elf32[..51]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn core_memory_segments() -> Result<(), Box<dyn Error>> {
+        let core = Elf::parse(&synth_core())?;
+
+        assert!(matches!(core.ehdr().ty(), EType::CORE));
+        assert_eq!(core.phentries().len(), 2);
+
+        assert_eq!(core.vaddr_to_offset(0x7fff_0004), Some(0xb4));
+        assert_eq!(
+            core.data_at_vaddr(0x7fff_0000, 16),
+            Some(&b"ELFLIB-CORE-MARK"[..])
+        );
+        assert_eq!(
+            core.data_at_vaddr(0x40_0000, 8),
+            Some(&0xf4c3_9090u64.to_le_bytes()[..])
+        );
+
+        /* Not dumped tail of the segment */
+        assert_eq!(core.vaddr_to_offset(0x40_0008), None);
+        assert_eq!(core.data_at_vaddr(0x40_0004, 8), None);
+        assert_eq!(core.vaddr_to_offset(0x50_0000), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn vaddr_translation() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/hello")?;
+
+        for name in [".interp", ".text", ".rodata", ".dynamic", ".data"] {
+            let sh = elf.shentries().get(name).unwrap();
+
+            assert_eq!(
+                elf.vaddr_to_offset(sh.addr().0),
+                Some(sh.offset().0),
+                "{}",
+                name
+            );
+            assert_eq!(
+                elf.vaddr_to_offset(sh.addr().0 + sh.size() - 1),
+                Some(sh.offset().0 + sh.size() - 1),
+                "{}",
+                name
+            );
+        }
+
+        /* In memory only */
+        let bss = elf.shentries().get(".bss").unwrap();
+        assert_eq!(elf.vaddr_to_offset(bss.addr().0), None);
+        assert_eq!(elf.vaddr_to_offset(bss.addr().0 + bss.size() - 1), None);
+
+        /* Out of all segments */
+        assert_eq!(elf.vaddr_to_offset(0x10_0000), None);
+        assert_eq!(elf.vaddr_to_offset(u64::MAX), None);
+
+        let interp = elf.shentries().get(".interp").unwrap();
+        assert_eq!(
+            elf.data_at_vaddr(interp.addr().0, *interp.size() as usize),
+            Some(&b"/lib64/ld-linux-x86-64.so.2\0"[..])
+        );
+
+        /* Across the end of the .data into the .bss */
+        assert!(elf.data_at_vaddr(bss.addr().0 - 8, 8).is_some());
+        assert_eq!(elf.data_at_vaddr(bss.addr().0 - 8, 9), None);
+        assert_eq!(elf.data_at_vaddr(bss.addr().0, 1), None);
+        assert_eq!(elf.data_at_vaddr(u64::MAX, 1), None);
+
+        Ok(())
     }
 }
@@ -1,15 +1,20 @@
+use alloc::{string::String, vec, vec::Vec};
+
 use getset::CopyGetters;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+
+use crate::view::EIData;
 
 
 ////////////////////////////////////////////////////////////////////////////////
-//// Elf Header
+// Elf Header
 
-#[derive(CopyGetters, Default, Deserialize)]
+#[repr(C)]
+#[derive(CopyGetters, Default, Deserialize, Serialize)]
 #[getset(get_copy = "pub")]
 pub struct E64Hdr {
     /// Elf Header Identifier
-    ident: EIdent,
+    pub(crate) ident: EIdent,
 
     /// Object file type:
     ///
@@ -25,58 +30,58 @@ pub struct E64Hdr {
     /// | ET_LOPROC | 0xff00 | Processor-specific
     /// | ET_HIPROC | 0xffff | Processor-specific
     ///
-    ty: u16,
+    pub(crate) ty: u16,
 
     /// required architecture
     /// ref https://refspecs.linuxfoundation.org/elf/gabi4+/ch4.eheader.html#elfid
-    machine: u16,
+    pub(crate) machine: u16,
 
     /// EV_VERSION, should be 1
-    version: u32,
+    pub(crate) version: u32,
 
-    entry: u64,
+    pub(crate) entry: u64,
 
-    phoff: u64,
-    shoff: u64,
+    pub(crate) phoff: u64,
+    pub(crate) shoff: u64,
 
-    flags: u32,
-    ehsize: u16,  // header size
+    pub(crate) flags: u32,
+    pub(crate) ehsize: u16,  // header size
 
-    ph_tab_entry_size: u16,  // Bytes of One Entry of Program Header Table
-    ph_tab_entry_num: u16,  // Program Header Table Entry Count
+    pub(crate) ph_tab_entry_size: u16,  // Bytes of One Entry of Program Header Table
+    pub(crate) ph_tab_entry_num: u16,  // Program Header Table Entry Count
 
-    sh_tab_entry_size: u16,  // Section Header Table Entry Size
-    sh_tab_entry_num: u16,   // Section Header Table Entry Number
+    pub(crate) sh_tab_entry_size: u16,  // Section Header Table Entry Size
+    pub(crate) sh_tab_entry_num: u16,   // Section Header Table Entry Number
 
-    sh_strtab_idx: u16  // Section header string table index
+    pub(crate) sh_strtab_idx: u16  // Section header string table index
 }
 
-#[derive(CopyGetters, Default, Deserialize)]
+#[derive(CopyGetters, Default, Deserialize, Serialize)]
 #[getset(get_copy = "pub")]
 pub struct E32Hdr {
-    ident: EIdent,
-    ty: u16,
-    machine: u16,
-    version: u32,
-    entry: u32,
+    pub(crate) ident: EIdent,
+    pub(crate) ty: u16,
+    pub(crate) machine: u16,
+    pub(crate) version: u32,
+    pub(crate) entry: u32,
 
-    phoff: u64,
-    shoff: u64,
+    pub(crate) phoff: u32,
+    pub(crate) shoff: u32,
 
-    flags: u32,
-    ehsize: u16,
+    pub(crate) flags: u32,
+    pub(crate) ehsize: u16,
 
-    ph_tab_entry_size: u16,
-    ph_tab_entry_num: u16,
+    pub(crate) ph_tab_entry_size: u16,
+    pub(crate) ph_tab_entry_num: u16,
 
-    sh_tab_entry_size: u16,
-    sh_tab_entry_num: u16,
+    pub(crate) sh_tab_entry_size: u16,
+    pub(crate) sh_tab_entry_num: u16,
 
-    sh_strtab_idx: u16
+    pub(crate) sh_strtab_idx: u16
 }
 
 #[repr(C)]
-#[derive(CopyGetters, Default, Deserialize, Clone, Copy)]
+#[derive(CopyGetters, Default, Deserialize, Serialize, Clone, Copy)]
 #[getset(get_copy = "pub")]
 pub struct EIdent {
     /// Indicate file type
@@ -106,16 +111,19 @@ pub struct EIdent {
     /// or no version values are specified for the ABI determined by a particular value of the EI_OSABI byte,
     /// the value 0 is used to indicate unspecified.
     pub(crate) abiversion: u8,
-    _pad: [u8; 6],
 
-    /// Fixed value: 16, indicate that EIdent bytes
+    /// EI_PAD, unused and zero
+    pub(crate) pad: [u8; 6],
+
+    /// The last byte of the padding, EI_NIDENT (16) is the size of
+    /// `e_ident` rather than a stored value
     pub(crate) nident: u8,
 }
 
 
 
 ////////////////////////////////////////////////////////////////////////////////
-//// Program Header
+// Program Header
 
 #[derive(CopyGetters, Default, Deserialize)]
 #[getset(get_copy = "pub")]
@@ -184,111 +192,324 @@ pub struct E32Phdr {
 
 
 ////////////////////////////////////////////////////////////////////////////////
-//// Section Header
+// Section Header
 
-#[derive(CopyGetters, Default, Deserialize)]
+#[derive(CopyGetters, Default, Deserialize, Serialize)]
 #[getset(get_copy = "pub")]
 pub struct E64Shdr {
     /// Section name - string tab idx
-    name: u32,
+    pub(crate) name: u32,
 
     /// Section type
-    ty: u32,
+    pub(crate) ty: u32,
 
     /// Section flags
-    flags: u64,
+    pub(crate) flags: u64,
 
     /// Section virtual address at executation
-    addr: u64,
+    pub(crate) addr: u64,
 
     /// Section offset
-    offset: u64,
+    pub(crate) offset: u64,
 
     /// Section size
-    size: u64,
+    pub(crate) size: u64,
 
     /// Holds a section header table index link,
     /// whose interpretion depends on the section type.
-    link: u32,
+    pub(crate) link: u32,
 
     /// Holds extra information, whose interpretion depends on the section type
-    info: u32,
+    pub(crate) info: u32,
 
     /// The value of sh_addr must be congruent to 0, modulo the value of sh_addralign.
     /// Currently, only 0 and positive integral powers of two are allowed.
     /// Values 0 and 1 mean the section has no alignment constraints.
-    addr_align: u64,
+    pub(crate) addr_align: u64,
 
     /// Some sections hold a table of fixed-size entries,
     /// such as a symbol table. For such a section,
     /// this member gives the size in bytes of each entry.
     /// The member contains 0 if the section does not hold
     /// a table of fixed-size entries
-    ent_size: u64
+    pub(crate) ent_size: u64
 }
 
-#[derive(CopyGetters, Default, Deserialize)]
+#[derive(CopyGetters, Default, Deserialize, Serialize)]
 #[getset(get_copy = "pub")]
 pub struct E32Shdr {
-    name: u32,
-    ty: u32,
-    flags: u32,
-    addr: u32,
-    offset: u32,
-    size: u32,
-    link: u32,
-    info: u32,
-    addr_align: u32,
-    ent_size: u32
+    pub(crate) name: u32,
+    pub(crate) ty: u32,
+    pub(crate) flags: u32,
+    pub(crate) addr: u32,
+    pub(crate) offset: u32,
+    pub(crate) size: u32,
+    pub(crate) link: u32,
+    pub(crate) info: u32,
+    pub(crate) addr_align: u32,
+    pub(crate) ent_size: u32
 }
 
 
 ////////////////////////////////////////////////////////////////////////////////
-//// Section Data
+// Section Data
 
 #[derive(Clone)]
 pub struct StrTab(Vec<u8>);
 
+/// Build a string table, interning the strings.
+///
+/// A string already in the table (as a whole or as the tail of a longer
+/// one, like `bar` in `foobar`) is reused instead of being appended again.
+#[derive(Clone)]
+pub struct StrTabBuilder(Vec<u8>);
+
+/// Bounds-checked, endianness-aware reader over the bytes of one section.
+///
+/// `read_*` take an offset relative to the section start,
+/// `next_*` read at the cursor position and advance it.
+#[derive(Clone, Copy)]
+pub struct SectionData<'a> {
+    raw: &'a [u8],
+    endian: EIData,
+    pos: usize,
+}
+
 
 ////////////////////////////////////////////////////////////////////////////////
-//// Symbol Table
+// Symbol Table
 
-#[derive(CopyGetters, Default, Deserialize, Debug)]
+#[derive(CopyGetters, Default, Deserialize, Serialize, Debug)]
 #[getset(get_copy = "pub")]
 pub struct E64Sym {
-    name: u32,
+    pub(crate) name: u32,
 
     /// type and binding
-    info: u8,
+    pub(crate) info: u8,
 
     /// the first 2 bit indicates that visibility
     /// the last 6 bit is unspecified
-    other: u8,
+    pub(crate) other: u8,
 
     /// Section index
-    shndx: u16,
+    pub(crate) shndx: u16,
 
     /// This member gives the value of the associated symbol.
     /// Depending on the context, this may be an absolute value, an address, and so on;
-    value: u64,
-    size: u64
+    pub(crate) value: u64,
+    pub(crate) size: u64
 }
 
 
-#[derive(CopyGetters, Default, Deserialize)]
+#[derive(CopyGetters, Default, Deserialize, Serialize)]
 #[getset(get_copy = "pub")]
 pub struct E32Sym {
+    pub(crate) name: u32,
+    pub(crate) value: u32,
+    pub(crate) size: u32,
+    pub(crate) info: u8,
+    pub(crate) other: u8,
+    pub(crate) shndx: u16
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Relocation
+
+#[derive(CopyGetters, Default, Deserialize)]
+#[getset(get_copy = "pub")]
+pub struct E64Rela {
+    offset: u64,
+
+    /// Symbol index (high 32 bit) and relocation type (low 32 bit)
+    info: u64,
+
+    /// `Elf64_Sxword`, signed
+    addend: i64
+}
+
+#[derive(CopyGetters, Default, Deserialize)]
+#[getset(get_copy = "pub")]
+pub struct E32Rela {
+    offset: u32,
+
+    /// Symbol index (high 24 bit) and relocation type (low 8 bit)
+    info: u32,
+
+    addend: i32
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Dynamic Section
+
+#[derive(CopyGetters, Default, Deserialize)]
+#[getset(get_copy = "pub")]
+pub struct E64Dyn {
+    tag: i64,
+
+    /// Integer value or address, depends on the tag
+    val: u64
+}
+
+#[derive(CopyGetters, Default, Deserialize)]
+#[getset(get_copy = "pub")]
+pub struct E32Dyn {
+    tag: i32,
+    val: u32
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Symbol Versioning (GNU)
+//
+// Same layout for both 32 and 64 bit class
+
+/// `.gnu.version_d` entry
+#[derive(CopyGetters, Default, Deserialize)]
+#[getset(get_copy = "pub")]
+pub struct E64Verdef {
+    /// Revision, should be 1
+    version: u16,
+
+    /// VER_FLG_BASE 0x1: version of the file itself
+    /// VER_FLG_WEAK 0x2: weak version identifier
+    flags: u16,
+
+    /// Version index, referred by `.gnu.version`
+    ndx: u16,
+
+    /// Number of associated Verdaux, the first is the version name,
+    /// the others are the parents
+    cnt: u16,
+
+    hash: u32,
+
+    /// Offset to the first Verdaux, from the start of this Verdef
+    aux: u32,
+
+    /// Offset to the next Verdef, from the start of this Verdef (0 for end)
+    next: u32
+}
+
+#[derive(CopyGetters, Default, Deserialize)]
+#[getset(get_copy = "pub")]
+pub struct E64Verdaux {
+    /// Version or dependency name - dynstr idx
+    name: u32,
+
+    /// Offset to the next Verdaux, from the start of this Verdaux
+    next: u32
+}
+
+/// `.gnu.version_r` entry, one for each needed file
+#[derive(CopyGetters, Default, Deserialize)]
+#[getset(get_copy = "pub")]
+pub struct E64Verneed {
+    /// Revision, should be 1
+    version: u16,
+
+    /// Number of associated Vernaux
+    cnt: u16,
+
+    /// Needed file name - dynstr idx
+    file: u32,
+
+    /// Offset to the first Vernaux, from the start of this Verneed
+    aux: u32,
+
+    /// Offset to the next Verneed, from the start of this Verneed
+    next: u32
+}
+
+#[derive(CopyGetters, Default, Deserialize)]
+#[getset(get_copy = "pub")]
+pub struct E64Vernaux {
+    hash: u32,
+
+    /// VER_FLG_WEAK 0x2
+    flags: u16,
+
+    /// Version index, referred by `.gnu.version`
+    other: u16,
+
+    /// Version name - dynstr idx
     name: u32,
-    value: u32,
-    size: u32,
-    info: u8,
-    other: u8,
-    shndx: u16
+
+    /// Offset to the next Vernaux, from the start of this Vernaux
+    next: u32
 }
 
+
 ////////////////////////////////////////////////////////////////////////////////
-//// Implementations
+// Implementations
+
+
+impl EIdent {
+    /// Identification of the current version (`EV_CURRENT`)
+    pub(crate) fn new(class: u8, data: u8, osabi: u8) -> Self {
+        Self {
+            magic_nums: *b"\x7fELF",
+            class,
+            data,
+            version: 1,
+            osabi,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<E32Shdr> for E64Shdr {
+    fn from(shdr: E32Shdr) -> Self {
+        Self {
+            name: shdr.name,
+            ty: shdr.ty,
+            flags: shdr.flags.into(),
+            addr: shdr.addr.into(),
+            offset: shdr.offset.into(),
+            size: shdr.size.into(),
+            link: shdr.link,
+            info: shdr.info,
+            addr_align: shdr.addr_align.into(),
+            ent_size: shdr.ent_size.into(),
+        }
+    }
+}
+
+impl From<E32Sym> for E64Sym {
+    fn from(sym: E32Sym) -> Self {
+        Self {
+            name: sym.name,
+            info: sym.info,
+            other: sym.other,
+            shndx: sym.shndx,
+            value: sym.value.into(),
+            size: sym.size.into(),
+        }
+    }
+}
+
+impl From<E32Rela> for E64Rela {
+    /// The symbol index and the type are moved to their 64-bit places
+    fn from(rela: E32Rela) -> Self {
+        let sym = u64::from(rela.info >> 8);
+        let ty = u64::from(rela.info & 0xff);
+
+        Self {
+            offset: rela.offset.into(),
+            info: sym << 32 | ty,
+            addend: rela.addend.into(),
+        }
+    }
+}
 
+impl From<E32Dyn> for E64Dyn {
+    fn from(dyn_: E32Dyn) -> Self {
+        Self {
+            tag: dyn_.tag.into(),
+            val: dyn_.val.into(),
+        }
+    }
+}
 
 impl StrTab {
     pub fn empty() -> Self {
@@ -299,55 +520,364 @@ impl StrTab {
         Self(vec)
     }
 
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// The null-terminated string at `idx` (or up to the end of table),
+    /// decoded as UTF-8, an invalid sequence is replaced by `U+FFFD`
     pub fn get(&self, idx: usize) -> Option<String> {
-        if idx >= self.0.len() {
+        let rest = self.0.get(idx..)?;
+
+        if rest.is_empty() {
             return None;
         }
 
-        let mut s = String::new();
+        let end = rest.iter().position(|&b| b == 0).unwrap_or(rest.len());
 
-        for i in idx..self.0.len() {
-            if self.0[i] == 0 {
-                break;
-            }
+        Some(String::from_utf8_lossy(&rest[..end]).into_owned())
+    }
 
-            s.push(self.0[i] as char)
+    /// As `get` but borrowed, `None` also for an invalid UTF-8 string
+    pub fn get_str(&self, idx: usize) -> Option<&str> {
+        let rest = self.0.get(idx..)?;
+
+        if rest.is_empty() {
+            return None;
         }
 
-        Some(s)
+        let end = rest.iter().position(|&b| b == 0).unwrap_or(rest.len());
+
+        core::str::from_utf8(&rest[..end]).ok()
     }
 
+    /// All the null-terminated strings after the leading empty one
     pub fn str_vec(&self) -> Vec<String> {
-        let mut str_vec = vec![];
+        let Some(rest) = self.0.get(1..) else {
+            return vec![];
+        };
+
+        let mut str_vec: Vec<String> = rest
+            .split(|&b| b == 0)
+            .map(|raw| String::from_utf8_lossy(raw).into_owned())
+            .collect();
+
+        /* After the last null, it isn't terminated */
+        str_vec.pop();
+
+        str_vec
+    }
 
-        if self.0.is_empty() {
-            return str_vec;
+}
+
+
+impl StrTabBuilder {
+    /// A table holding only the leading empty string
+    pub fn new() -> Self {
+        Self(vec![0])
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Offset of `s` in the table, appended if it isn't there yet
+    pub fn intern(&mut self, s: &str) -> u32 {
+        let mut needle = s.as_bytes().to_vec();
+        needle.push(0);
+
+        if let Some(off) =
+            self.0.windows(needle.len()).position(|win| win == needle)
+        {
+            return off as u32;
+        }
+
+        let off = self.0.len();
+        self.0.extend_from_slice(&needle);
+
+        off as u32
+    }
+
+    pub fn build(self) -> StrTab {
+        StrTab(self.0)
+    }
+}
+
+impl Default for StrTabBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<&StrTab> for StrTabBuilder {
+    /// Keep the existing strings (and their offsets)
+    fn from(strtab: &StrTab) -> Self {
+        Self(strtab.as_bytes().to_vec())
+    }
+}
+
+macro_rules! read_int {
+    ($name:ident, $next:ident, $ty:ty) => {
+        pub fn $name(&self, off: usize) -> Option<$ty> {
+            let bytes = self.read_array(off)?;
+
+            Some(match self.endian {
+                EIData::MSB => <$ty>::from_be_bytes(bytes),
+                _ => <$ty>::from_le_bytes(bytes),
+            })
+        }
+
+        pub fn $next(&mut self) -> Option<$ty> {
+            let val = self.$name(self.pos)?;
+            self.pos += core::mem::size_of::<$ty>();
+
+            Some(val)
+        }
+    };
+}
+
+impl<'a> SectionData<'a> {
+    pub fn new(raw: &'a [u8], endian: EIData) -> Self {
+        Self { raw, endian, pos: 0 }
+    }
+
+    pub fn raw(&self) -> &'a [u8] {
+        self.raw
+    }
+
+    pub fn len(&self) -> usize {
+        self.raw.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.raw.is_empty()
+    }
+
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Move the cursor, it's allowed to be at the end but not beyond it.
+    pub fn seek(&mut self, pos: usize) -> Option<()> {
+        if pos > self.raw.len() {
+            return None;
         }
 
-        let mut s = String::new();
-        for i in 1..self.0.len() {
-            if self.0[i] == 0 {
-                str_vec.push(s);
-                s = String::new();
-                continue;
+        self.pos = pos;
+
+        Some(())
+    }
+
+    /// Bytes left after the cursor
+    pub fn remaining(&self) -> usize {
+        self.raw.len().saturating_sub(self.pos)
+    }
+
+    read_int!(read_u8, next_u8, u8);
+    read_int!(read_u16, next_u16, u16);
+    read_int!(read_u32, next_u32, u32);
+    read_int!(read_u64, next_u64, u64);
+
+    /// Unsigned LEB128 at the cursor, `None` if it doesn't fit in 64 bits
+    pub fn next_uleb128(&mut self) -> Option<u64> {
+        let mut val = 0u64;
+
+        for shift in (0..64).step_by(7) {
+            let byte = self.next_u8()?;
+            val |= u64::from(byte & 0x7f).checked_shl(shift)?;
+
+            if byte & 0x80 == 0 {
+                return Some(val);
             }
+        }
+
+        None
+    }
+
+    /// Signed LEB128 at the cursor, `None` if it doesn't fit in 64 bits
+    pub fn next_sleb128(&mut self) -> Option<i64> {
+        let mut val = 0i64;
+
+        for shift in (0..64).step_by(7) {
+            let byte = self.next_u8()?;
+            val |= i64::from(byte & 0x7f) << shift;
+
+            if byte & 0x80 == 0 {
+                /* Sign extend from the last group */
+                if shift + 7 < 64 && byte & 0x40 != 0 {
+                    val |= -1 << (shift + 7);
+                }
 
-            s.push(self.0[i] as char);
+                return Some(val);
+            }
         }
 
-        str_vec
+        None
+    }
+
+    pub fn read_bytes(&self, off: usize, len: usize) -> Option<&'a [u8]> {
+        self.raw.get(off..off.checked_add(len)?)
+    }
+
+    /// Null-terminated UTF-8 string starting at `off`
+    pub fn read_cstr(&self, off: usize) -> Option<&'a str> {
+        let rest = self.raw.get(off..)?;
+        let end = rest.iter().position(|&b| b == 0)?;
+
+        core::str::from_utf8(&rest[..end]).ok()
+    }
+
+    fn read_array<const N: usize>(&self, off: usize) -> Option<[u8; N]> {
+        self.read_bytes(off, N)?.try_into().ok()
+    }
+
+    fn next_array<const N: usize>(&mut self) -> Option<[u8; N]> {
+        let bytes = self.read_array(self.pos)?;
+        self.pos += N;
+
+        Some(bytes)
+    }
+}
+
+/// A raw structure of the file, read and written field by field in the
+/// byte order of the file, there is no padding between the fields.
+pub(crate) trait Raw: Sized {
+    fn read(data: &mut SectionData) -> Option<Self>;
+
+    fn write(&self, out: &mut Vec<u8>, endian: EIData);
+}
+
+macro_rules! raw_int {
+    ($($ty:ty),*) => {$(
+        impl Raw for $ty {
+            fn read(data: &mut SectionData) -> Option<Self> {
+                let bytes = data.next_array()?;
+
+                Some(match data.endian {
+                    EIData::MSB => <$ty>::from_be_bytes(bytes),
+                    _ => <$ty>::from_le_bytes(bytes),
+                })
+            }
+
+            fn write(&self, out: &mut Vec<u8>, endian: EIData) {
+                out.extend_from_slice(&match endian {
+                    EIData::MSB => self.to_be_bytes(),
+                    _ => self.to_le_bytes(),
+                });
+            }
+        }
+    )*};
+}
+
+/// The fields in the order of the file layout
+macro_rules! raw_struct {
+    ($ty:ty { $($field:ident),* $(,)? }) => {
+        impl Raw for $ty {
+            fn read(data: &mut SectionData) -> Option<Self> {
+                Some(Self {
+                    $($field: Raw::read(data)?,)*
+                })
+            }
+
+            fn write(&self, out: &mut Vec<u8>, endian: EIData) {
+                $(self.$field.write(out, endian);)*
+            }
+        }
+    };
+}
+
+raw_int!(u8, u16, u32, u64, i32, i64);
+
+impl<const N: usize> Raw for [u8; N] {
+    fn read(data: &mut SectionData) -> Option<Self> {
+        data.next_array()
     }
 
+    fn write(&self, out: &mut Vec<u8>, _endian: EIData) {
+        out.extend_from_slice(self);
+    }
 }
 
+raw_struct!(EIdent {
+    magic_nums, class, data, version, osabi, abiversion, pad, nident,
+});
+
+raw_struct!(E64Hdr {
+    ident, ty, machine, version, entry, phoff, shoff, flags, ehsize,
+    ph_tab_entry_size, ph_tab_entry_num, sh_tab_entry_size,
+    sh_tab_entry_num, sh_strtab_idx,
+});
+
+raw_struct!(E32Hdr {
+    ident, ty, machine, version, entry, phoff, shoff, flags, ehsize,
+    ph_tab_entry_size, ph_tab_entry_num, sh_tab_entry_size,
+    sh_tab_entry_num, sh_strtab_idx,
+});
+
+raw_struct!(E64Phdr {
+    ty, flags, offset, vaddr, paddr, filesz, memsz, align,
+});
+
+raw_struct!(E32Phdr {
+    ty, offset, vaddr, paddr, filesz, memsz, flags, align,
+});
+
+raw_struct!(E64Shdr {
+    name, ty, flags, addr, offset, size, link, info, addr_align, ent_size,
+});
+
+raw_struct!(E32Shdr {
+    name, ty, flags, addr, offset, size, link, info, addr_align, ent_size,
+});
+
+raw_struct!(E64Sym { name, info, other, shndx, value, size });
+
+raw_struct!(E32Sym { name, value, size, info, other, shndx });
+
+raw_struct!(E64Rela { offset, info, addend });
+
+raw_struct!(E32Rela { offset, info, addend });
+
+raw_struct!(E64Dyn { tag, val });
+
+raw_struct!(E32Dyn { tag, val });
+
+raw_struct!(E64Verdef { version, flags, ndx, cnt, hash, aux, next });
+
+raw_struct!(E64Verdaux { name, next });
+
+raw_struct!(E64Verneed { version, cnt, file, aux, next });
+
+raw_struct!(E64Vernaux { hash, flags, other, name, next });
+
 
 #[cfg(test)]
-mod tests {
-    use std::mem::size_of;
+pub(crate) mod tests {
+    use std::{fs, mem::size_of};
+
+    use crate::view::{EIClass, EIData};
+
+    use super::{E64Hdr, EIdent, Raw, SectionData, StrTab, StrTabBuilder};
 
-    use crate::view::EIClass;
+    /// Read the `E64Hdr` at the start of `bytes` and write it back,
+    /// it should be the same bytes as the original header region.
+    pub(crate) fn assert_header_roundtrip(bytes: &[u8]) {
+        let region = &bytes[..size_of::<E64Hdr>()];
 
-    use super::{E64Hdr, EIdent};
+        for endian in [EIData::LSB, EIData::MSB] {
+            let mut data = SectionData::new(region, endian);
+            let ehdr = E64Hdr::read(&mut data).unwrap();
+            let mut out = vec![];
+            ehdr.write(&mut out, endian);
+
+            assert_eq!(data.remaining(), 0);
+            assert_eq!(out, region);
+        }
+    }
 
     #[test]
     fn echo_size() {
@@ -355,4 +885,91 @@ mod tests {
         println!("EIdent: {}", size_of::<EIdent>());
         println!("E64Hdr: {}", size_of::<E64Hdr>());
     }
+
+    #[test]
+    fn strtab_builder_interns() {
+        let mut builder = StrTabBuilder::new();
+
+        assert_eq!(builder.intern(""), 0);
+        assert_eq!(builder.intern("foobar"), 1);
+        assert_eq!(builder.intern("bar"), 4);
+        assert_eq!(builder.intern("foobar"), 1);
+        assert_eq!(builder.intern("foo"), 8);
+
+        let strtab = builder.build();
+
+        assert_eq!(strtab.as_bytes(), b"\0foobar\0foo\0");
+        assert_eq!(strtab.get(4).unwrap(), "bar");
+
+        let mut builder = StrTabBuilder::from(&strtab);
+        assert_eq!(builder.intern("oo"), 9);
+        assert_eq!(builder.len(), 12);
+
+        let mut builder = StrTabBuilder::from(&StrTab::empty());
+        assert_eq!(builder.intern(""), 0);
+    }
+
+    #[test]
+    fn leb128() {
+        /* DWARF spec examples */
+        let raw = [2, 0x7f, 0x80, 1, 0xe5, 0x8e, 0x26, 0x7e, 0x80, 0x7f];
+        let mut cursor = SectionData::new(&raw, EIData::LSB);
+
+        assert_eq!(cursor.next_uleb128(), Some(2));
+        assert_eq!(cursor.next_uleb128(), Some(127));
+        assert_eq!(cursor.next_uleb128(), Some(128));
+        assert_eq!(cursor.next_uleb128(), Some(624_485));
+        assert_eq!(cursor.next_sleb128(), Some(-2));
+        assert_eq!(cursor.next_sleb128(), Some(-128));
+        assert_eq!(cursor.remaining(), 0);
+
+        /* Unterminated, or longer than 64 bits */
+        let mut cursor = SectionData::new(&[0x80], EIData::LSB);
+        assert_eq!(cursor.next_uleb128(), None);
+
+        let mut cursor = SectionData::new(&[0xff; 11], EIData::LSB);
+        assert_eq!(cursor.next_sleb128(), None);
+    }
+
+    #[test]
+    fn strtab_utf8() {
+        let raw = b"\0caf\xc3\xa9\0bad\xff\0\xe4\xb8\xad";
+        let strtab = StrTab::new(raw.to_vec());
+
+        assert_eq!(strtab.get(1).unwrap(), "café");
+        assert_eq!(strtab.get(4).unwrap(), "\u{e9}");
+        assert_eq!(strtab.get(7).unwrap(), "bad\u{fffd}");
+        assert_eq!(strtab.get(12).unwrap(), "中");
+        assert_eq!(strtab.get(15), None);
+
+        assert_eq!(strtab.str_vec(), ["café", "bad\u{fffd}"]);
+        assert!(StrTab::empty().str_vec().is_empty());
+    }
+
+    #[test]
+    fn header_round_trip() {
+        for name in ["arr", "libarr.so.1", "hello", "gonote"] {
+            let bytes = fs::read(format!("./draft/{}", name)).unwrap();
+
+            assert_header_roundtrip(&bytes);
+        }
+    }
+
+    #[test]
+    fn section_data_endian() {
+        let raw = [0x12, 0x34, 0x56, 0x78, b'o', b'k', 0];
+
+        let le = SectionData::new(&raw, EIData::LSB);
+        let mut be = SectionData::new(&raw, EIData::MSB);
+
+        assert_eq!(le.read_u32(0), Some(0x7856_3412));
+        assert_eq!(be.read_u16(2), Some(0x5678));
+        assert_eq!(le.read_cstr(4), Some("ok"));
+        assert_eq!(le.read_u64(0), None);
+        assert_eq!(le.read_u32(usize::MAX), None);
+
+        assert_eq!(be.next_u32(), Some(0x1234_5678));
+        assert_eq!(be.remaining(), 3);
+        assert_eq!(be.next_u32(), None);
+    }
 }
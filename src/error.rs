@@ -0,0 +1,140 @@
+use core::{error::Error, fmt::Display};
+
+use alloc::string::String;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Elf Error
+
+#[derive(Debug)]
+pub enum ElfError {
+    /// No section has this name
+    SectionNotFound(String),
+
+    /// The section can't be removed, the null section 0 or the section
+    /// header string table
+    SectionRequired(String),
+
+    /// A declared count or size is beyond the `LoadOptions` cap, or a value
+    /// doesn't fit its 32-bit field when writing
+    LimitExceeded {
+        what: &'static str,
+        declared: usize,
+        limit: usize,
+    },
+
+    /// A header or table goes beyond the end of the file
+    Truncated {
+        what: &'static str,
+        offset: usize,
+        size: usize,
+        file_size: usize,
+    },
+
+    /// The file is a known non-ELF format
+    NotElf { detected: Format },
+
+    /// Neither the ELF magic nor any other known one
+    BadMagic,
+
+    /// A raw structure can't be decoded (e.g. of an unknown ELF class)
+    Malformed(String),
+
+    /// Reading the input failed (`Elf::from_reader`)
+    #[cfg(feature = "std")]
+    Io(std::io::Error),
+}
+
+/// Executable format (other than ELF) recognized by the magic
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// `0xfeedface` in either byte order
+    MachO32,
+
+    /// `0xfeedfacf` in either byte order
+    MachO64,
+
+    /// `0xcafebabe`, fat (universal) Mach-O, or a Java class file
+    MachOFat,
+
+    /// `MZ`, DOS / PE (Windows)
+    Pe,
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Implementations
+
+impl Display for ElfError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::SectionNotFound(name) => {
+                write!(f, "section `{}` not found", name)
+            }
+            Self::SectionRequired(name) => {
+                write!(f, "section `{}` is required", name)
+            }
+            Self::LimitExceeded {
+                what,
+                declared,
+                limit,
+            } => {
+                write!(f, "{} {} exceeds the limit {}", what, declared, limit)
+            }
+            Self::Truncated {
+                what,
+                offset,
+                size,
+                file_size,
+            } => write!(
+                f,
+                "{} of {} bytes at {:#x} is beyond the end of file ({} bytes)",
+                what, size, offset, file_size
+            ),
+            Self::NotElf { detected } => {
+                write!(f, "not an ELF file, but {}", detected)
+            }
+            Self::BadMagic => write!(f, "bad magic, not an ELF file"),
+            Self::Malformed(msg) => write!(f, "malformed ELF: {}", msg),
+            #[cfg(feature = "std")]
+            Self::Io(err) => write!(f, "read error: {}", err),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for ElfError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl Format {
+    /// Recognize the format by the leading magic
+    pub fn detect(bytes: &[u8]) -> Option<Self> {
+        let magic = bytes.get(..4).map(|magic| {
+            u32::from_be_bytes([magic[0], magic[1], magic[2], magic[3]])
+        });
+
+        match magic {
+            Some(0xfeed_face | 0xcefa_edfe) => Some(Self::MachO32),
+            Some(0xfeed_facf | 0xcffa_edfe) => Some(Self::MachO64),
+            Some(0xcafe_babe) => Some(Self::MachOFat),
+            _ if bytes.starts_with(b"MZ") => Some(Self::Pe),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::MachO32 => write!(f, "32-bit Mach-O"),
+            Self::MachO64 => write!(f, "64-bit Mach-O"),
+            Self::MachOFat => write!(f, "fat Mach-O (or Java class)"),
+            Self::Pe => write!(f, "PE/DOS executable"),
+        }
+    }
+}
+
+impl Error for ElfError {}
@@ -1,20 +1,32 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 pub mod view;
 pub mod data;
 pub mod ctrl;
+pub mod error;
+pub mod validate;
+pub mod readelf;
+pub mod builder;
 
+pub use crate::builder::ElfBuilder;
 pub use crate::ctrl::Elf;
+pub use crate::error::ElfError;
 
 
 #[cfg(test)]
 mod tests {
-    use std::error::Error;
+    use std::{error::Error, fs};
 
-    use crate::Elf;
+    use crate::{data::tests::assert_header_roundtrip, Elf};
 
     #[test]
     fn it_works() -> Result<(), Box<dyn Error>> {
         let elf = Elf::load("./draft/arr")?;
 
+        assert_header_roundtrip(&fs::read("./draft/arr")?);
+
         println!("{:#?}", elf);
 
         Ok(())
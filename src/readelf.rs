@@ -0,0 +1,852 @@
+use core::fmt::Write;
+
+use alloc::{
+    borrow::ToOwned,
+    format,
+    string::{String, ToString},
+};
+
+use crate::{
+    data::SectionData,
+    view::{
+        EIClass, EIData, EMachine, OsAbi, PhType, SHFlagBit, SHType, SymType,
+        SymValue, SymView, SymVisi, SHFLAGS, SID,
+    },
+    Elf,
+};
+
+
+/// Which symbol table to format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolTableKind {
+    /// `.symtab`
+    Symtab,
+
+    /// `.dynsym`
+    Dynsym,
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Names (as GNU readelf)
+
+fn class_name(class: EIClass) -> &'static str {
+    match class {
+        EIClass::Invalid => "none",
+        EIClass::Bit32 => "ELF32",
+        EIClass::Bit64 => "ELF64",
+    }
+}
+
+fn data_name(data: EIData) -> &'static str {
+    match data {
+        EIData::Invalid => "none",
+        EIData::LSB => "2's complement, little endian",
+        EIData::MSB => "2's complement, big endian",
+    }
+}
+
+fn osabi_name(osabi: u8) -> String {
+    match osabi {
+        0 => "UNIX - System V",
+        1 => "UNIX - HP-UX",
+        2 => "UNIX - NetBSD",
+        3 => "UNIX - GNU",
+        6 => "UNIX - Solaris",
+        7 => "UNIX - AIX",
+        8 => "UNIX - IRIX",
+        9 => "UNIX - FreeBSD",
+        10 => "UNIX - TRU64",
+        11 => "Novell - Modesto",
+        12 => "UNIX - OpenBSD",
+        13 => "VMS - OpenVMS",
+        14 => "HP - Non-Stop Kernel",
+        15 => "AROS",
+        16 => "FenixOS",
+        17 => "Nuxi CloudABI",
+        18 => "Stratus Technologies OpenVOS",
+        x => return format!("<unknown: {:x}>", x),
+    }
+    .to_owned()
+}
+
+fn type_name(ty: u16, pie: bool) -> String {
+    match ty {
+        0 => "NONE (None)",
+        1 => "REL (Relocatable file)",
+        2 => "EXEC (Executable file)",
+        3 if pie => "DYN (Position-Independent Executable file)",
+        3 => "DYN (Shared object file)",
+        4 => "CORE (Core file)",
+        0xff00..=0xffff => return format!("Processor Specific: ({:x})", ty),
+        0xfe00..=0xfeff => return format!("OS Specific: ({:x})", ty),
+        x => return format!("<unknown>: {:x}", x),
+    }
+    .to_owned()
+}
+
+fn machine_name(machine: u16) -> String {
+    match machine {
+        0 => "None",
+        2 => "Sparc",
+        3 => "Intel 80386",
+        7 => "Intel 80860",
+        8 => "MIPS R3000",
+        19 => "Intel 80960",
+        20 => "PowerPC",
+        21 => "PowerPC64",
+        22 => "IBM S/390",
+        40 => "ARM",
+        43 => "Sparc v9",
+        50 => "Intel IA-64",
+        51 => "Stanford MIPS-X",
+        62 => "Advanced Micro Devices X86-64",
+        91 => "picoJava",
+        183 => "AArch64",
+        243 => "RISC-V",
+        247 => "Linux BPF",
+        258 => "LoongArch",
+        x => return format!("<unknown>: 0x{:x}", x),
+    }
+    .to_owned()
+}
+
+fn section_type_name(ty: u32, machine: &EMachine) -> String {
+    match ty {
+        0 => "NULL",
+        1 => "PROGBITS",
+        2 => "SYMTAB",
+        3 => "STRTAB",
+        4 => "RELA",
+        5 => "HASH",
+        6 => "DYNAMIC",
+        7 => "NOTE",
+        8 => "NOBITS",
+        9 => "REL",
+        10 => "SHLIB",
+        11 => "DYNSYM",
+        14 => "INIT_ARRAY",
+        15 => "FINI_ARRAY",
+        16 => "PREINIT_ARRAY",
+        17 => "GROUP",
+        18 => "SYMTAB SECTION INDICES",
+        19 => "RELR",
+        0x6fff_4c03 => "LLVM_ADDRSIG",
+        0x6fff_fff5 => "GNU_ATTRIBUTES",
+        0x6fff_fff6 => "GNU_HASH",
+        0x6fff_fff7 => "GNU_LIBLIST",
+        0x6fff_fffd => "VERDEF",
+        0x6fff_fffe => "VERNEED",
+        0x6fff_ffff => "VERSYM",
+        0x7000_0001 if matches!(machine, EMachine::X86_64) => "X86_64_UNWIND",
+        0x6000_0000..=0x6fff_ffff => {
+            return format!("LOOS+{:#x}", ty - 0x6000_0000)
+        }
+        0x7000_0000..=0x7fff_ffff => {
+            return format!("LOPROC+{:#x}", ty - 0x7000_0000)
+        }
+        0x8000_0000..=0xffff_ffff => {
+            return format!("LOUSER+{:#x}", ty - 0x8000_0000)
+        }
+        x => return format!("<unknown>: {:x}", x),
+    }
+    .to_owned()
+}
+
+fn segment_type_name(ty: u32) -> String {
+    match ty {
+        0 => "NULL",
+        1 => "LOAD",
+        2 => "DYNAMIC",
+        3 => "INTERP",
+        4 => "NOTE",
+        5 => "SHLIB",
+        6 => "PHDR",
+        7 => "TLS",
+        0x6474_e550 => "GNU_EH_FRAME",
+        0x6474_e551 => "GNU_STACK",
+        0x6474_e552 => "GNU_RELRO",
+        0x6474_e553 => "GNU_PROPERTY",
+        0x6474_e554 => "GNU_SFRAME",
+        0x6474_e555..=0x6474_f554 => {
+            return format!("GNU_MBIND+{:#x}", ty - 0x6474_e555)
+        }
+        0x6000_0000..=0x6fff_ffff => {
+            return format!("LOOS+{:#x}", ty - 0x6000_0000)
+        }
+        0x7000_0000..=0x7fff_ffff => {
+            return format!("LOPROC+{:#x}", ty - 0x7000_0000)
+        }
+        x => return format!("<unknown>: {:x}", x),
+    }
+    .to_owned()
+}
+
+fn sym_type_name(ty: u8, gnu: bool) -> String {
+    match ty {
+        0 => "NOTYPE",
+        1 => "OBJECT",
+        2 => "FUNC",
+        3 => "SECTION",
+        4 => "FILE",
+        5 => "COMMON",
+        6 => "TLS",
+        8 => "RELC",
+        9 => "SRELC",
+        10 if gnu => "IFUNC",
+        10..=12 => return format!("<OS specific>: {}", ty),
+        13..=15 => return format!("<processor specific>: {}", ty),
+        x => return format!("<unknown>: {}", x),
+    }
+    .to_owned()
+}
+
+fn sym_bind_name(bind: u8, gnu: bool) -> String {
+    match bind {
+        0 => "LOCAL",
+        1 => "GLOBAL",
+        2 => "WEAK",
+        10 if gnu => "UNIQUE",
+        10..=12 => return format!("<OS specific>: {}", bind),
+        13..=15 => return format!("<processor specific>: {}", bind),
+        x => return format!("<unknown>: {}", x),
+    }
+    .to_owned()
+}
+
+fn sym_visi_name(visi: SymVisi) -> &'static str {
+    match visi {
+        SymVisi::Default => "DEFAULT",
+        SymVisi::Internal => "INTERNAL",
+        SymVisi::Hidden => "HIDDEN",
+        SymVisi::Protected => "PROTECTED",
+    }
+}
+
+fn sym_ndx_name(shndx: SID) -> String {
+    match shndx {
+        SID::Undef => "UND".to_owned(),
+        SID::Abs => "ABS".to_owned(),
+        SID::Common => "COM".to_owned(),
+        SID::Proc(x) => format!("PRC[0x{:04x}]", x),
+        SID::OS(x) => format!("OS [0x{:04x}]", x),
+        SID::XIndex => "RSV[0xffff]".to_owned(),
+        SID::Normal(x) if x >= 0xff00 => format!("RSV[0x{:04x}]", x),
+        SID::Normal(x) => format!("{:3}", x),
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Implementations
+
+impl Elf {
+    /// The ELF header, formatted exactly as `readelf -h`
+    pub fn as_readelf_header(&self) -> String {
+        let ehdr = self.ehdr();
+        let ident = ehdr.ident();
+        let raw = SectionData::new(self.raw(), ident.data());
+
+        /* Read from the raw header, the views don't keep unknown values */
+        let ty = raw.read_u16(16).unwrap_or_default();
+        let machine = raw.read_u16(18).unwrap_or_default();
+
+        /* The real count of the extended numbering after the raw one */
+        let sh0 = self.shentries().get_index(0);
+        let extended = |raw: String, real: Option<u64>| match real {
+            Some(real) if real != 0 => format!("{} ({})", raw, real),
+            _ => raw,
+        };
+
+        let mut out = String::from("ELF Header:\n  Magic:   ");
+        for byte in self.raw().iter().take(16) {
+            write!(out, "{:02x} ", byte).unwrap();
+        }
+        out.push('\n');
+
+        let mut line = |label: &str, value: String| {
+            writeln!(out, "  {:<35}{}", label, value).unwrap();
+        };
+
+        line("Class:", class_name(ident.class()).to_owned());
+        line("Data:", data_name(ident.data()).to_owned());
+        line(
+            "Version:",
+            match ident.version() {
+                0 => "0".to_owned(),
+                1 => "1 (current)".to_owned(),
+                x => format!("{} <unknown>", x),
+            },
+        );
+        line("OS/ABI:", osabi_name(ident.osabi().into()));
+        line("ABI Version:", ident.abiversion().to_string());
+        line("Type:", type_name(ty, self.has_pie_flag()));
+        line("Machine:", machine_name(machine));
+        line("Version:", format!("0x{:x}", ehdr.version()));
+        line("Entry point address:", format!("0x{:x}", ehdr.entry().0));
+        line(
+            "Start of program headers:",
+            format!("{} (bytes into file)", ehdr.prog_hdr_offset().0),
+        );
+        line(
+            "Start of section headers:",
+            format!("{} (bytes into file)", ehdr.section_hdr_offset().0),
+        );
+        line("Flags:", format!("0x{:x}", ehdr.flags()));
+        line(
+            "Size of this header:",
+            format!("{} (bytes)", ehdr.elf_hdr_sz()),
+        );
+        line(
+            "Size of program headers:",
+            format!("{} (bytes)", ehdr.prog_hdr_tab_ent_sz()),
+        );
+        line(
+            "Number of program headers:",
+            extended(
+                ehdr.prog_hdr_tab_ent_num().to_string(),
+                sh0.filter(|_| *ehdr.prog_hdr_tab_ent_num() == 0xffff)
+                    .map(|sh| sh.info as u64),
+            ),
+        );
+        line(
+            "Size of section headers:",
+            format!("{} (bytes)", ehdr.section_hdr_ent_sz()),
+        );
+        line(
+            "Number of section headers:",
+            extended(
+                ehdr.section_hdr_ent_num().to_string(),
+                sh0.filter(|_| *ehdr.section_hdr_ent_num() == 0)
+                    .map(|sh| sh.size),
+            ),
+        );
+        line(
+            "Section header string table index:",
+            extended(
+                usize::from(*ehdr.section_str_tab_idx()).to_string(),
+                sh0.filter(|_| *ehdr.section_str_tab_idx() == SID::XIndex)
+                    .map(|sh| sh.link as u64),
+            ),
+        );
+
+        out
+    }
+
+    /// The section header table, formatted exactly as `readelf -S -W`
+    pub fn format_section_headers(&self) -> String {
+        let ehdr = self.ehdr();
+        let machine = ehdr.machine();
+        let shentries = self.shentries();
+
+        if shentries.is_empty() {
+            return "\nThere are no sections in this file.\n".to_owned();
+        }
+
+        let (addr_col, addr_width) = match ehdr.ident().class() {
+            EIClass::Bit32 => ("Addr    ", 8),
+            _ => ("Address         ", 16),
+        };
+
+        let mut out = format!(
+            "There {} {} section header{}, starting at offset 0x{:x}:\n\n",
+            if shentries.len() == 1 { "is" } else { "are" },
+            shentries.len(),
+            if shentries.len() == 1 { "" } else { "s" },
+            ehdr.section_hdr_offset().0
+        );
+
+        writeln!(out, "Section Headers:").unwrap();
+        writeln!(
+            out,
+            "  [Nr] Name              Type            {} Off    Size   ES \
+             Flg Lk Inf Al",
+            addr_col
+        )
+        .unwrap();
+
+        for (i, sh) in shentries.iter().enumerate() {
+            writeln!(
+                out,
+                "  [{:2}] {:<17} {:<15.15} {:0aw$x} {:06x} {:06x} {:02x} \
+                 {:>3} {:2} {:3} {:2}",
+                i,
+                sh.name(),
+                section_type_name(u32::from(sh.ty), machine),
+                sh.addr.0,
+                sh.offset.0,
+                sh.size,
+                sh.ent_size,
+                sh.flags().readelf_letters_for(machine),
+                sh.link,
+                sh.info,
+                sh.addr_align,
+                aw = addr_width
+            )
+            .unwrap();
+        }
+
+        out.push_str(
+            "Key to Flags:\n  \
+             W (write), A (alloc), X (execute), M (merge), S (strings), \
+             I (info),\n  \
+             L (link order), O (extra OS processing required), G (group), \
+             T (TLS),\n  \
+             C (compressed), x (unknown), o (OS specific), E (exclude),\n",
+        );
+        out.push_str("  ");
+        if matches!(ehdr.ident().osabi(), OsAbi::Linux | OsAbi::FreeBSD) {
+            out.push_str("R (retain), ");
+        }
+        out.push_str(match machine {
+            EMachine::X86_64 => {
+                "D (mbind), l (large), p (processor specific)\n"
+            }
+            _ => "D (mbind), p (processor specific)\n",
+        });
+
+        out
+    }
+
+    /// The program header table and the section to segment mapping,
+    /// formatted exactly as `readelf -l -W`
+    pub fn format_program_headers(&self) -> String {
+        let ehdr = self.ehdr();
+        let phentries = self.phentries();
+
+        if phentries.is_empty() {
+            return "\nThere are no program headers in this file.\n"
+                .to_owned();
+        }
+
+        let ty = SectionData::new(self.raw(), ehdr.ident().data())
+            .read_u16(16)
+            .unwrap_or_default();
+        let bit32 = matches!(ehdr.ident().class(), EIClass::Bit32);
+
+        let mut out = String::new();
+        writeln!(
+            out,
+            "\nElf file type is {}",
+            type_name(ty, self.has_pie_flag())
+        )
+        .unwrap();
+        writeln!(out, "Entry point 0x{:x}", ehdr.entry().0).unwrap();
+        writeln!(
+            out,
+            "There {} {} program header{}, starting at offset {}",
+            if phentries.len() == 1 { "is" } else { "are" },
+            phentries.len(),
+            if phentries.len() == 1 { "" } else { "s" },
+            ehdr.prog_hdr_offset().0
+        )
+        .unwrap();
+
+        writeln!(out, "\nProgram Headers:").unwrap();
+        out.push_str(if bit32 {
+            "  Type           Offset   VirtAddr   PhysAddr   FileSiz MemSiz  \
+             Flg Align\n"
+        } else {
+            "  Type           Offset   VirtAddr           PhysAddr           \
+             FileSiz  MemSiz   Flg Align\n"
+        });
+
+        for ph in phentries.iter() {
+            let (aw, sw) = if bit32 { (8, 5) } else { (16, 6) };
+
+            writeln!(
+                out,
+                "  {:<14.14} 0x{:06x} 0x{:0aw$x} 0x{:0aw$x} 0x{:0sw$x} \
+                 0x{:0sw$x} {} {}",
+                segment_type_name(u32::from(ph.ty)),
+                ph.offset,
+                ph.vaddr.0,
+                ph.paddr.0,
+                ph.filesz,
+                ph.memsz,
+                ph.flags.letters(),
+                if ph.align == 0 {
+                    "0".to_owned()
+                } else {
+                    format!("{:#x}", ph.align)
+                },
+            )
+            .unwrap();
+
+            if matches!(ph.ty, PhType::INTERP) {
+                writeln!(
+                    out,
+                    "      [Requesting program interpreter: {}]",
+                    self.interpreter().unwrap_or_default()
+                )
+                .unwrap();
+            }
+        }
+
+        if self.shentries().is_empty() {
+            return out;
+        }
+
+        out.push_str(
+            "\n Section to Segment mapping:\n  Segment Sections...\n",
+        );
+        for i in 0..phentries.len() {
+            write!(out, "   {:02}     ", i).unwrap();
+
+            for sh in self.sections_in_segment(i) {
+                write!(out, "{} ", sh.name()).unwrap();
+            }
+
+            out.push('\n');
+        }
+
+        out
+    }
+
+    /// The symbol table, formatted as `readelf -s -W`, empty if there's
+    /// no such table.
+    ///
+    /// Unlike readelf, the section offset (or alignment) values of a
+    /// relocatable object are in decimal, only the addresses are in hex.
+    pub fn format_symbols(&self, which: SymbolTableKind) -> String {
+        self.format_symbols_with(which, |sym| sym.name().clone())
+    }
+
+    /// As `format_symbols`, with the names demangled as `readelf -s -W -C`
+    #[cfg(feature = "demangle")]
+    pub fn format_symbols_demangled(&self, which: SymbolTableKind) -> String {
+        self.format_symbols_with(which, |sym| {
+            sym.demangled().unwrap_or_else(|| sym.name().clone())
+        })
+    }
+
+    fn format_symbols_with(
+        &self,
+        which: SymbolTableKind,
+        name_of: fn(&SymView) -> String,
+    ) -> String {
+        let (ty, symtab) = match which {
+            SymbolTableKind::Symtab => (SHType::SYMtab, self.symtab()),
+            SymbolTableKind::Dynsym => (SHType::DYNSYM, self.dynsym()),
+        };
+
+        let Some(section) = self.shentries().first_by_type(ty) else {
+            return String::new();
+        };
+
+        let ehdr = self.ehdr();
+        let gnu =
+            matches!(ehdr.ident().osabi(), OsAbi::Linux | OsAbi::FreeBSD);
+        let (value_col, width) = match ehdr.ident().class() {
+            EIClass::Bit32 => ("Value ", 8),
+            _ => ("Value         ", 16),
+        };
+
+        let mut out = format!(
+            "\nSymbol table '{}' contains {} {}:\n",
+            section.name(),
+            symtab.len(),
+            if symtab.len() == 1 {
+                "entry"
+            } else {
+                "entries"
+            }
+        );
+        writeln!(
+            out,
+            "   Num:    {} Size Type    Bind   Vis      Ndx Name",
+            value_col
+        )
+        .unwrap();
+
+        for (i, sym) in symtab.iter().enumerate() {
+            let value = match *sym.value() {
+                SymValue::VirAddr(addr) => {
+                    format!("{:0w$x}", addr.0, w = width)
+                }
+                SymValue::Undefined => format!("{:0w$x}", 0, w = width),
+                SymValue::SectionOffset(val) | SymValue::Alignment(val) => {
+                    format!("{:>w$}", val, w = width)
+                }
+            };
+            let size = if *sym.size() < 100_000 {
+                format!("{:5}", sym.size())
+            } else {
+                format!("{:#x}", sym.size())
+            };
+
+            let mut name = name_of(sym);
+            if name.is_empty() && matches!(sym.ty(), SymType::Section) {
+                name = sym.section_name(self).unwrap_or_default().to_owned();
+            }
+            if matches!(which, SymbolTableKind::Dynsym) {
+                name.push_str(&self.dynsym_version_suffix(i, sym));
+            }
+
+            writeln!(
+                out,
+                "{:6}: {} {} {:<7} {:<6} {:<8} {} {}",
+                i,
+                value,
+                size,
+                sym_type_name(sym.raw_info() & 0xf, gnu),
+                sym_bind_name(sym.raw_info() >> 4, gnu),
+                sym_visi_name(*sym.visi()),
+                sym_ndx_name(*sym.shndx()),
+                name
+            )
+            .unwrap();
+        }
+
+        out
+    }
+
+    /// Version after the name of the `i`th dynamic symbol as readelf,
+    /// `@@VER` (default) or `@VER` (hidden) of a definition,
+    /// `@VER (ndx)` of a reference
+    fn dynsym_version_suffix(&self, i: usize, sym: &SymView) -> String {
+        let Some(&raw) = self.versym().get(i) else {
+            return String::new();
+        };
+        let ndx = raw & 0x7fff;
+
+        if ndx <= 1 {
+            return String::new();
+        }
+
+        if !matches!(sym.shndx(), SID::Undef) && raw != 0x8001 {
+            if let Some(def) = self.verdef().iter().find(|def| def.ndx == ndx)
+            {
+                /* The symbol of the version definition itself */
+                if def.name == *sym.name() {
+                    return String::new();
+                }
+
+                let at = if raw & 0x8000 != 0 { "@" } else { "@@" };
+                return format!("{}{}", at, def.name);
+            }
+        }
+
+        self.verneed()
+            .iter()
+            .flat_map(|need| need.aux.iter())
+            .find(|aux| aux.other == ndx)
+            .map(|aux| format!("@{} ({})", aux.name, ndx))
+            .unwrap_or_default()
+    }
+
+    /// Flags of the section as the `Flg` column of `readelf -S`,
+    /// with the machine-specific letters
+    pub fn section_flags_string(&self, name: &str) -> Option<String> {
+        let sh = self.shentries().get(name)?;
+
+        Some(sh.flags().readelf_letters_for(self.ehdr().machine()))
+    }
+}
+
+impl SHFLAGS {
+    /// Flag letters as the `Flg` column of `readelf -S`, lowest bit first.
+    ///
+    /// Each unknown generic bit is an `x`, the first OS-specific bit which
+    /// isn't named is an `o` covering the rest of them, and the same for
+    /// `p` of the processor-specific bits (`E` included if it comes later).
+    pub fn readelf_letters(&self) -> String {
+        self.readelf_letters_for(&EMachine::None)
+    }
+
+    pub(crate) fn readelf_letters_for(&self, machine: &EMachine) -> String {
+        const SHF_MASKOS: u64 = 0x0ff0_0000;
+        const SHF_MASKPROC: u64 = 0xf000_0000;
+        const SHF_EXCLUDE: u64 = 0x8000_0000;
+        const SHF_X86_64_LARGE: u64 = 0x1000_0000;
+        const SHF_GNU_RETAIN: u64 = 0x20_0000;
+        const SHF_GNU_MBIND: u64 = 0x100_0000;
+
+        let x86_64 = matches!(machine, EMachine::X86_64);
+        let mut bits = self.bits();
+        let mut letters = String::new();
+
+        while bits != 0 {
+            let flag = bits & bits.wrapping_neg();
+            bits &= !flag;
+
+            letters.push(match flag {
+                0x1 => 'W',
+                0x2 => 'A',
+                0x4 => 'X',
+                0x10 => 'M',
+                0x20 => 'S',
+                0x40 => 'I',
+                0x80 => 'L',
+                0x100 => 'O',
+                0x200 => 'G',
+                0x400 => 'T',
+                0x800 => 'C',
+                SHF_EXCLUDE => 'E',
+                SHF_X86_64_LARGE if x86_64 => 'l',
+                SHF_GNU_RETAIN if self.has(SHFlagBit::GnuRetain) => 'R',
+                SHF_GNU_MBIND if self.has(SHFlagBit::GnuMbind) => 'D',
+                _ if flag & SHF_MASKOS != 0 => {
+                    bits &= !SHF_MASKOS;
+                    'o'
+                }
+                _ if flag & SHF_MASKPROC != 0 => {
+                    bits &= !SHF_MASKPROC;
+                    'p'
+                }
+                _ => 'x',
+            });
+        }
+
+        letters
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::{error::Error, fs};
+
+    use super::SymbolTableKind;
+    use crate::{
+        view::{SHFlagBit, SHFLAGS},
+        Elf,
+    };
+
+    #[test]
+    fn readelf_header_golden() -> Result<(), Box<dyn Error>> {
+        for name in ["arr", "arr32", "libarr.so.1", "hello"] {
+            let elf = Elf::load(format!("./draft/{}", name))?;
+            let golden =
+                fs::read_to_string(format!("./draft/{}.readelf-h", name))?;
+
+            assert_eq!(elf.as_readelf_header(), golden, "{}", name);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn readelf_sections_golden() -> Result<(), Box<dyn Error>> {
+        for name in ["arr", "arr32", "libarr.so.1", "hello", "flags"] {
+            let elf = Elf::load(format!("./draft/{}", name))?;
+            let golden =
+                fs::read_to_string(format!("./draft/{}.readelf-S", name))?;
+
+            assert_eq!(elf.format_section_headers(), golden, "{}", name);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn readelf_segments_golden() -> Result<(), Box<dyn Error>> {
+        for name in ["arr", "libarr.so.1", "hello"] {
+            let elf = Elf::load(format!("./draft/{}", name))?;
+            let golden =
+                fs::read_to_string(format!("./draft/{}.readelf-l", name))?;
+
+            assert_eq!(elf.format_program_headers(), golden, "{}", name);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn readelf_symbols_golden() -> Result<(), Box<dyn Error>> {
+        for name in ["libarr.so.1", "hello"] {
+            let elf = Elf::load(format!("./draft/{}", name))?;
+            let golden =
+                fs::read_to_string(format!("./draft/{}.readelf-s", name))?;
+
+            let out = elf.format_symbols(SymbolTableKind::Dynsym)
+                + &elf.format_symbols(SymbolTableKind::Symtab);
+
+            assert_eq!(out, golden, "{}", name);
+        }
+
+        /* Section offsets in decimal */
+        let elf = Elf::load("./draft/arr")?;
+        let out = elf.format_symbols(SymbolTableKind::Symtab);
+
+        assert!(out.contains(
+            "     6:               15    83 FUNC    GLOBAL DEFAULT    1 sum\n"
+        ));
+        assert!(out.contains(" SECTION LOCAL  DEFAULT    1 .text\n"));
+        assert_eq!(elf.format_symbols(SymbolTableKind::Dynsym), "");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "demangle")]
+    #[test]
+    fn demangled_symbols() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/mangled")?;
+        let names = |out: String| {
+            out.lines()
+                .skip(3)
+                .map(|line| line[59..].to_owned())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            names(elf.format_symbols_demangled(SymbolTableKind::Symtab)),
+            [
+                "",
+                "add(int, int)",
+                "core::fmt::write",
+                "mycrate::foo",
+                "plain_c",
+            ]
+        );
+
+        /* The raw names are kept */
+        let symtab = elf.symtab();
+
+        assert_eq!(symtab[1].name(), "_Z3addii");
+        assert_eq!(symtab[4].demangled(), None);
+        assert!(elf
+            .format_symbols(SymbolTableKind::Symtab)
+            .contains(" _RNvCs1234_7mycrate3foo\n"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn section_flags_golden() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/flags")?;
+        let golden = fs::read_to_string("./draft/flags.readelf-S")?;
+
+        let mut lines = golden.lines().skip_while(|l| !l.contains("Flg"));
+        let col = lines.next().unwrap().find("Flg").unwrap();
+
+        for line in lines.take_while(|l| l.starts_with("  [")) {
+            let name = line[7..24].trim();
+            let flags = line[col..col + 3].trim();
+
+            if name.is_empty() {
+                continue;
+            }
+
+            assert_eq!(
+                elf.section_flags_string(name).unwrap(),
+                flags,
+                "{}",
+                name
+            );
+        }
+
+        /* Without the machine, the large bit is a processor-specific one */
+        assert_eq!(SHFLAGS::from(0x1000_0000).readelf_letters(), "p");
+        assert_eq!(SHFLAGS::from_osabi(0x20_0000, 0).readelf_letters(), "o");
+
+        /* One letter of each bit, in the bit order */
+        assert_eq!(SHFlagBit::TLS.to_string(), "T");
+        assert_eq!(SHFLAGS::from(0x8000_0032).letters(), "AMSE");
+        assert_eq!(SHFLAGS::from_osabi(0x20_0006, 3).letters(), "AXR");
+        assert_eq!(SHFLAGS::from(0x0030_0001).letters(), "Wo");
+        assert_eq!(SHFLAGS::from(0x1000_0008).letters(), "xp");
+
+        Ok(())
+    }
+}
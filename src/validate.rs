@@ -0,0 +1,398 @@
+use alloc::{string::String, vec, vec::Vec};
+
+use crate::{
+    view::{EIClass, SHType},
+    Elf,
+};
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Validation Issue
+
+/// Structural problem found by [`Elf::validate`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationIssue {
+    /// Symbol count of `.dynsym` (by section size) disagrees with
+    /// the count implied by `.gnu.hash`, a sign of truncation or tampering.
+    SymbolCountMismatch { dynsym: usize, gnu_hash: usize },
+
+    /// `link` or `info` (where it's a section index) of the section refers
+    /// to no section, e.g. after an edit without fixing the references.
+    DanglingSectionRef {
+        section: String,
+        field: &'static str,
+        index: u32,
+    },
+
+    /// The file image doesn't start with `\x7fELF`
+    BadMagic,
+
+    /// `EI_VERSION` or `e_version` isn't `EV_CURRENT` (1)
+    BadVersion { field: &'static str, version: u32 },
+
+    /// The padding of `e_ident` (`EI_PAD` up to the byte 15) isn't zero,
+    /// some tools stash their marks in it
+    NonZeroPadding { pad: [u8; 7] },
+
+    /// `e_shstrndx` refers to no section
+    ShStrTabIndexOutOfRange { index: usize, num: usize },
+
+    /// `[offset, offset + size)` of a section with file content is beyond
+    /// the end of the file
+    SectionOutOfFile {
+        section: String,
+        offset: u64,
+        size: u64,
+        file_size: usize,
+    },
+
+    /// `link` of a symbol table doesn't refer to a string table
+    SymbolStrTabLink { section: String, link: u32 },
+
+    /// A header size field (`e_ehsize`, `e_phentsize`, `e_shentsize`)
+    /// disagrees with the structure size of the class
+    HeaderSizeMismatch {
+        field: &'static str,
+        declared: u16,
+        expected: u16,
+    },
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Implementations
+
+impl Elf {
+    /// Run all the cheap sanity checks, collecting every problem found
+    pub fn validate(&self) -> Result<(), Vec<ValidationIssue>> {
+        let mut issues = vec![];
+
+        if !self.raw().starts_with(b"\x7fELF") {
+            issues.push(ValidationIssue::BadMagic);
+        }
+
+        self.check_ident(&mut issues);
+        self.check_header_sizes(&mut issues);
+        self.check_shstrtab_index(&mut issues);
+        self.check_section_ranges(&mut issues);
+        self.check_dynsym_count(&mut issues);
+        self.check_section_refs(&mut issues);
+        self.check_symbol_strtab_links(&mut issues);
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
+        }
+    }
+
+    fn check_ident(&self, issues: &mut Vec<ValidationIssue>) {
+        const EV_CURRENT: u32 = 1;
+
+        let ehdr = self.ehdr();
+        let versions = [
+            ("EI_VERSION", u32::from(ehdr.ident().version())),
+            ("e_version", *ehdr.version()),
+        ];
+
+        for (field, version) in versions {
+            if version != EV_CURRENT {
+                issues.push(ValidationIssue::BadVersion { field, version });
+            }
+        }
+
+        let pad = ehdr.ident().pad();
+
+        if pad != [0; 7] {
+            issues.push(ValidationIssue::NonZeroPadding { pad });
+        }
+    }
+
+    fn check_header_sizes(&self, issues: &mut Vec<ValidationIssue>) {
+        let ehdr = self.ehdr();
+
+        let (ehsize, phentsize, shentsize) = match ehdr.ident().class() {
+            EIClass::Bit32 => (52, 32, 40),
+            _ => (64, 56, 64),
+        };
+
+        let mut fields = vec![("e_ehsize", *ehdr.elf_hdr_sz(), ehsize)];
+
+        if *ehdr.prog_hdr_tab_ent_num() > 0 {
+            fields.push((
+                "e_phentsize",
+                *ehdr.prog_hdr_tab_ent_sz(),
+                phentsize,
+            ));
+        }
+        if !self.shentries().is_empty() {
+            fields.push((
+                "e_shentsize",
+                *ehdr.section_hdr_ent_sz(),
+                shentsize,
+            ));
+        }
+
+        for (field, declared, expected) in fields {
+            if declared != expected {
+                issues.push(ValidationIssue::HeaderSizeMismatch {
+                    field,
+                    declared,
+                    expected,
+                });
+            }
+        }
+    }
+
+    fn check_shstrtab_index(&self, issues: &mut Vec<ValidationIssue>) {
+        let num = self.shentries().len();
+
+        if num > 0 && self.shstrtab_index().is_none() {
+            issues.push(ValidationIssue::ShStrTabIndexOutOfRange {
+                index: usize::from(*self.ehdr().section_str_tab_idx()),
+                num,
+            });
+        }
+    }
+
+    fn check_section_ranges(&self, issues: &mut Vec<ValidationIssue>) {
+        let file_size = self.raw().len();
+
+        for sh in self.shentries() {
+            if matches!(sh.ty, SHType::NULL | SHType::NOBITS) {
+                continue;
+            }
+
+            let end = sh.offset.0.checked_add(sh.size);
+
+            if end.map_or(true, |end| end > file_size as u64) {
+                issues.push(ValidationIssue::SectionOutOfFile {
+                    section: sh.name.clone(),
+                    offset: sh.offset.0,
+                    size: sh.size,
+                    file_size,
+                });
+            }
+        }
+    }
+
+    fn check_dynsym_count(&self, issues: &mut Vec<ValidationIssue>) {
+        let Some(sh) = self.shentries().first_by_type(SHType::DYNSYM) else {
+            return;
+        };
+
+        if sh.ent_size == 0 {
+            return;
+        }
+
+        let dynsym = (sh.size / sh.ent_size) as usize;
+
+        if let Some(gnu_hash) = self.gnu_hash_symbol_count() {
+            if dynsym != gnu_hash {
+                issues.push(ValidationIssue::SymbolCountMismatch {
+                    dynsym,
+                    gnu_hash,
+                });
+            }
+        }
+    }
+
+    fn check_section_refs(&self, issues: &mut Vec<ValidationIssue>) {
+        let num = self.shentries().0.len() as u32;
+
+        for sh in self.shentries().0.iter() {
+            let mut refs = vec![("link", sh.link)];
+
+            if sh.info_is_section_index() {
+                refs.push(("info", sh.info));
+            }
+
+            for (field, index) in refs {
+                if index >= num {
+                    issues.push(ValidationIssue::DanglingSectionRef {
+                        section: sh.name.clone(),
+                        field,
+                        index,
+                    });
+                }
+            }
+        }
+    }
+
+    fn check_symbol_strtab_links(&self, issues: &mut Vec<ValidationIssue>) {
+        for sh in self.shentries() {
+            if !matches!(sh.ty, SHType::SYMtab | SHType::DYNSYM) {
+                continue;
+            }
+
+            let linked = self.shentries().get_index(sh.link as usize);
+
+            if !linked
+                .is_some_and(|strtab| matches!(strtab.ty, SHType::STRtab))
+            {
+                issues.push(ValidationIssue::SymbolStrTabLink {
+                    section: sh.name.clone(),
+                    link: sh.link,
+                });
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use super::ValidationIssue;
+    use crate::Elf;
+
+    #[test]
+    fn dynsym_count_consistency() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/libarr.so.1")?;
+
+        assert_eq!(elf.gnu_hash_symbol_count(), Some(12));
+        assert_eq!(elf.validate(), Ok(()));
+
+        /* Truncate .dynsym by one entry (sh_size of its section header) */
+        let mut bytes = elf.to_bytes()?;
+        let shoff = elf.ehdr().section_hdr_offset().0 as usize;
+        let idx = elf
+            .shentries()
+            .0
+            .iter()
+            .position(|sh| sh.name() == ".dynsym")
+            .unwrap();
+        let size_off = shoff + idx * 64 + 32;
+        let size = elf.shentries().get(".dynsym").unwrap().size() - 24;
+        bytes[size_off..size_off + 8].copy_from_slice(&size.to_le_bytes());
+
+        let tampered = Elf::parse(&bytes)?;
+
+        assert_eq!(
+            tampered.validate(),
+            Err(vec![ValidationIssue::SymbolCountMismatch {
+                dynsym: 11,
+                gnu_hash: 12
+            }])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn dangling_section_refs() -> Result<(), Box<dyn Error>> {
+        let elf = Elf::load("./draft/arr")?;
+
+        assert_eq!(elf.validate(), Ok(()));
+
+        /* Point `link` and `info` of .rela.text (2) out of the 12 sections */
+        let mut bytes = elf.to_bytes()?;
+        let base = elf.ehdr().section_hdr_offset().0 as usize + 2 * 64;
+        bytes[base + 40..base + 44].copy_from_slice(&12u32.to_le_bytes());
+        bytes[base + 44..base + 48].copy_from_slice(&99u32.to_le_bytes());
+
+        let section = ".rela.text".to_owned();
+
+        assert_eq!(
+            Elf::parse(&bytes)?.validate(),
+            Err(vec![
+                ValidationIssue::DanglingSectionRef {
+                    section: section.clone(),
+                    field: "link",
+                    index: 12
+                },
+                ValidationIssue::DanglingSectionRef {
+                    section,
+                    field: "info",
+                    index: 99
+                },
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn header_and_table_checks() -> Result<(), Box<dyn Error>> {
+        for name in ["arr", "arr32", "libarr.so.1", "hello", "flags"] {
+            let elf = Elf::load(format!("./draft/{}", name))?;
+
+            assert_eq!(elf.validate(), Ok(()), "{}", name);
+        }
+
+        let elf = Elf::load("./draft/arr")?;
+        let shoff = elf.ehdr().section_hdr_offset().0 as usize;
+        let image = elf.to_bytes()?;
+        let tampered = |off: usize, val: &[u8]| {
+            let mut bytes = image.clone();
+            bytes[off..off + val.len()].copy_from_slice(val);
+
+            Elf::parse(&bytes).unwrap().validate().unwrap_err()
+        };
+
+        assert_eq!(
+            tampered(6, &[2]),
+            [ValidationIssue::BadVersion {
+                field: "EI_VERSION",
+                version: 2
+            }]
+        );
+        assert_eq!(
+            tampered(20, &0u32.to_le_bytes()),
+            [ValidationIssue::BadVersion {
+                field: "e_version",
+                version: 0
+            }]
+        );
+        assert_eq!(
+            tampered(10, &[0xca, 0xfe]),
+            [ValidationIssue::NonZeroPadding {
+                pad: [0, 0xca, 0xfe, 0, 0, 0, 0]
+            }]
+        );
+        assert_eq!(
+            tampered(15, &[16]),
+            [ValidationIssue::NonZeroPadding {
+                pad: [0, 0, 0, 0, 0, 0, 16]
+            }]
+        );
+        assert_eq!(
+            tampered(52, &63u16.to_le_bytes()),
+            [ValidationIssue::HeaderSizeMismatch {
+                field: "e_ehsize",
+                declared: 63,
+                expected: 64
+            }]
+        );
+        assert_eq!(
+            tampered(62, &50u16.to_le_bytes()),
+            [
+                ValidationIssue::ShStrTabIndexOutOfRange {
+                    index: 50,
+                    num: 12
+                }
+            ]
+        );
+
+        /* sh_offset of .data (3), sh_link of .symtab (9) */
+        assert_eq!(
+            tampered(shoff + 3 * 64 + 24, &0x10_0000u64.to_le_bytes()),
+            [ValidationIssue::SectionOutOfFile {
+                section: ".data".to_owned(),
+                offset: 0x10_0000,
+                size: 40,
+                file_size: elf.raw().len()
+            }]
+        );
+        assert_eq!(
+            tampered(shoff + 9 * 64 + 40, &1u32.to_le_bytes()),
+            [ValidationIssue::SymbolStrTabLink {
+                section: ".symtab".to_owned(),
+                link: 1
+            }]
+        );
+
+        Ok(())
+    }
+}
@@ -1,19 +1,36 @@
-use std::fmt::Debug;
-use std::mem::transmute;
+use core::{
+    fmt::{Debug, Display},
+    num::ParseIntError,
+    str::FromStr,
+};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+#[cfg(not(feature = "std"))]
+use alloc::collections::BTreeMap as HashMap;
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 
 use getset::{CopyGetters, Getters};
+use serde::Serialize;
 
-use crate::data::{E64Hdr, E64Phdr, StrTab};
+use crate::{
+    data::{E32Hdr, E32Phdr, E64Dyn, E64Hdr, E64Phdr, E64Rela, StrTab},
+    Elf,
+};
 
 
 ////////////////////////////////////////////////////////////////////////////////
-//// EIdent View
+// EIdent View
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 #[repr(transparent)]
 pub struct MagicNums(pub [u8; 4]);
 
-#[derive(Default, Debug, Clone, Copy)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum EIClass {
     #[default]
     Invalid,
@@ -21,7 +38,7 @@ pub enum EIClass {
     Bit64,
 }
 
-#[derive(Default, Debug, Clone, Copy)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum EIData {
     #[default]
     Invalid,
@@ -29,24 +46,78 @@ pub enum EIData {
     MSB,
 }
 
-#[derive(Debug, CopyGetters, Clone)]
+/// OS and ABI of the object (`EI_OSABI`)
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum OsAbi {
+    /// 0, UNIX System V, also ELFOSABI_NONE
+    #[default]
+    SysV,
+
+    /// 1
+    HPUX,
+
+    /// 2
+    NetBSD,
+
+    /// 3, GNU/Linux, also ELFOSABI_GNU
+    Linux,
+
+    /// 6
+    Solaris,
+
+    /// 7
+    AIX,
+
+    /// 8
+    IRIX,
+
+    /// 9
+    FreeBSD,
+
+    /// 10, Compaq TRU64 UNIX
+    TRU64,
+
+    /// 11, Novell Modesto
+    Modesto,
+
+    /// 12
+    OpenBSD,
+
+    /// 64, ARM EABI
+    ArmAeabi,
+
+    /// 97
+    Arm,
+
+    /// 255, embedded (standalone) application
+    Standalone,
+
+    /// Other value
+    Other(u8),
+}
+
+#[derive(Debug, CopyGetters, Clone, PartialEq, Eq, Serialize)]
 #[getset(get_copy = "pub")]
 pub struct EIdentView {
     pub(crate) magic_nums: MagicNums,
     pub(crate) class: EIClass,
     pub(crate) data: EIData,
     pub(crate) version: u8,
-    pub(crate) osabi: u8,
+    pub(crate) osabi: OsAbi,
     pub(crate) abiversion: u8,
+
+    /// EI_PAD, bytes 9 to 15 of `e_ident`, zero in a conforming file
+    pub(crate) pad: [u8; 7],
+
+    /// The last padding byte (a part of `pad` as well)
     pub(crate) nident: u8,
 }
 
 
 ////////////////////////////////////////////////////////////////////////////////
-//// ElfHeader View
+// ElfHeader View
 
-#[derive(Default, Debug, Clone)]
-#[repr(u16)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum EType {
     #[default]
     None,
@@ -55,18 +126,50 @@ pub enum EType {
     DYN,
     CORE,
 
-    LOOS = 0xfe00,
-    HIOS = 0xfeff,
+    /// 0xfe00
+    LOOS,
+    /// 0xfeff
+    HIOS,
+
+    /// 0xff00
+    LOPROC,
+    /// 0xffff
+    HIPROC,
+
+    /// Other value
+    Unknown(u16),
+}
+
+/// How much of the relocated data is made read-only, as `checksec` tells
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum RelroStatus {
+    /// No `PT_GNU_RELRO` segment
+    None,
+
+    /// The `PT_GNU_RELRO` segment without `BIND_NOW`, the `.got.plt` stays
+    /// writable for the lazy binding
+    Partial,
 
-    LOPROC = 0xff00,
-    HIPROC = 0xffff,
+    /// The `PT_GNU_RELRO` segment with `BIND_NOW`
+    Full,
 }
 
-#[derive(Clone, Copy)]
+/// What the file is for, the `EType::DYN` of a position-independent
+/// executable told from that of a shared object
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum FileKind {
+    Relocatable,
+    Executable,
+    SharedObject,
+    Pie,
+    Core,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
 #[repr(transparent)]
 pub struct Hex64(pub u64);
 
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize)]
 #[repr(u16)]
 pub enum EMachine {
     #[default]
@@ -81,16 +184,28 @@ pub enum EMachine {
     _960 = 19,  // Intel 80960
     PPC = 20,   // Power PC
     PPC64 = 21, // 64-bit Power PC
+    S390 = 22,  // IBM S/390
+
+    ARM = 40,     // ARM 32-bit
+    SPARCV9 = 43, // SPARC v9 64-bit
 
     IA64 = 50,  // Intel IA-64
     MIPSX = 51, // Stanford MIPS-X
 
     X86_64 = 62, // AMD x86-64 architecture
     PJ = 91,     // picoJava
+
+    AARCH64 = 183,   // ARM 64-bit
+    RISCV = 243,     // RISC-V
+    BPF = 247,       // Linux BPF
+    LOONGARCH = 258, // LoongArch
+
+    /// Other value
+    Unknown(u16),
 }
 
 /// Section Id
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Serialize)]
 pub enum SID {
     /// 0
     Undef,
@@ -119,51 +234,155 @@ pub enum SID {
 }
 
 
-#[derive(Debug, Clone, Getters)]
+#[derive(Debug, Clone, PartialEq, Eq, Getters, Serialize)]
 #[getset(get = "pub")]
 pub struct EHdrView {
     ident: EIdentView,
     ty: EType,
     machine: EMachine,
     version: u32,
-    entry: Hex64,
+    pub(crate) entry: Hex64,
     prog_hdr_offset: Hex64,
-    section_hdr_offset: Hex64,
+    pub(crate) section_hdr_offset: Hex64,
     flags: u32,
     elf_hdr_sz: u16,
     prog_hdr_tab_ent_sz: u16,
     prog_hdr_tab_ent_num: u16,
     section_hdr_ent_sz: u16,
-    section_hdr_ent_num: u16,
-    section_str_tab_idx: SID,
+    pub(crate) section_hdr_ent_num: u16,
+    pub(crate) section_str_tab_idx: SID,
+}
+
+/// `e_flags` decoded by the machine
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum EFlagsDecoded {
+    Arm(ArmEFlags),
+    Mips(MipsEFlags),
+
+    /// The flags of the other machines, as they are
+    Raw(Hex64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, CopyGetters, Serialize)]
+#[getset(get_copy = "pub")]
+pub struct ArmEFlags {
+    /// Mask 0xff00_0000, 0 for the old (GNU) ABI
+    eabi_version: u8,
+
+    float_abi: ArmFloatAbi,
+
+    /// BE8 code, 0x80_0000
+    be8: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ArmFloatAbi {
+    /// 0x200 (EABI 5)
+    Soft,
+
+    /// 0x400 (EABI 5)
+    Hard,
+
+    Unspecified,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, CopyGetters, Serialize)]
+#[getset(get_copy = "pub")]
+pub struct MipsEFlags {
+    abi: MipsAbi,
+    arch: MipsArch,
+
+    /// 0x1
+    noreorder: bool,
+
+    /// 0x2
+    pic: bool,
+
+    /// 0x4
+    cpic: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum MipsAbi {
+    /// 0x1000
+    O32,
+    /// 0x2000
+    O64,
+    /// 0x3000
+    EABI32,
+    /// 0x4000
+    EABI64,
+    /// 0x20 (`EF_MIPS_ABI2`)
+    N32,
+
+    /// None of the above, e.g. N64 which is implied by the 64-bit class
+    Unspecified,
+}
+
+/// ISA level, mask 0xf000_0000
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum MipsArch {
+    Mips1,
+    Mips2,
+    Mips3,
+    Mips4,
+    Mips5,
+    Mips32,
+    Mips64,
+    Mips32R2,
+    Mips64R2,
+    Mips32R6,
+    Mips64R6,
+
+    /// Other value (shifted right by 28)
+    Unknown(u8),
 }
 
 
 ////////////////////////////////////////////////////////////////////////////////
-//// Program Header View
+// Program Header View
 
-#[derive(Getters, Debug)]
+#[derive(Getters, Debug, Clone, PartialEq, Eq, Serialize)]
 #[getset(get = "pub")]
 pub struct PHdrView {
-    ty: PhType,
+    pub(crate) ty: PhType,
+
+    pub(crate) flags: PFLAGS,
 
-    flags: PFLAGS,
+    pub(crate) offset: u64,
 
-    offset: u64,
+    pub(crate) vaddr: Hex64,
 
-    vaddr: Hex64,
+    pub(crate) paddr: Hex64,
 
-    paddr: Hex64,
+    pub(crate) filesz: u64,
 
-    filesz: u64,
+    pub(crate) memsz: u64,
 
-    memsz: u64,
+    pub(crate) align: u64
+}
+
+/// Thread-local storage described by the TLS segment
+#[derive(Debug, Clone, PartialEq, Eq, Getters, Serialize)]
+#[getset(get = "pub")]
+pub struct TlsInfo {
+    /// Address of the initialization image (`.tdata`)
+    pub(crate) vaddr: Hex64,
+
+    /// Size of the whole TLS block, `.tbss` included
+    pub(crate) memsz: u64,
 
-    align: u64
+    /// Size of the initialized part
+    pub(crate) filesz: u64,
+
+    pub(crate) align: u64,
+
+    /// The defined TLS symbols, whose values are offsets in the block
+    pub(crate) symbols: Vec<SymView>,
 }
 
 /// (Program header entry) Segemnt Type
-#[derive(Default, Debug, Clone, Copy)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 #[repr(u32)]
 pub enum PhType {
     /// This type indicates this entry should be ignored
@@ -199,6 +418,15 @@ pub enum PhType {
     /// reserved for operating system-specified semnatics
     LOOS = 0x6000_0000,
 
+    /// 0x6474_e550, the segment of `.eh_frame_hdr`
+    GNUEHFRAME = 0x6474_e550,
+
+    /// 0x6474_e551, the flags of the stack (no file content)
+    GNUSTACK = 0x6474_e551,
+
+    /// 0x6474_e552, the segment made read-only after the relocation
+    GNURELRO = 0x6474_e552,
+
     /// reserved for operating system-specified semnatics
     HIOS = 0x6fff_ffff,
 
@@ -207,10 +435,18 @@ pub enum PhType {
 
     /// reserved for processor-specific semantics
     HOPROC = 0x7fff_ffff,
+
+    /// Other value in LOOS - HIOS
+    SPECOS(u32),
+
+    /// Other value in LOPROC - HIPROC
+    SPECPROC(u32),
+
+    Unknown(u32),
 }
 
 /// https://refspecs.linuxfoundation.org/elf/gabi4+/ch5.pheader.html#p_flags
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum PFlagBit {
     X,
     W,
@@ -220,19 +456,23 @@ pub enum PFlagBit {
 }
 
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct PFLAGS(Vec<PFlagBit>);
 
 pub struct E64PhEntries(Option<Vec<E64Phdr>>);
 
 
 ////////////////////////////////////////////////////////////////////////////////
-//// Section Header View
+// Section Header View
 
-#[derive(Debug, Clone, Getters)]
+#[derive(Debug, Clone, PartialEq, Eq, Getters, Serialize)]
 #[getset(get = "pub")]
 pub struct SHdrView {
     pub(crate) name: String,
+
+    /// Offset of the name in the section header string table
+    pub(crate) name_idx: u32,
+
     pub(crate) ty: SHType,
     pub(crate) flags: SHFLAGS,
     pub(crate) addr: Hex64,
@@ -246,7 +486,7 @@ pub struct SHdrView {
 
 
 /// https://refspecs.linuxfoundation.org/elf/gabi4+/ch4.sheader.html#sh_type
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum SHType {
     /// The section header doesn't have an associated value
     /// Other members of the section header have undefined value
@@ -288,6 +528,9 @@ pub enum SHType {
     /// reserved
     SHLIB,
 
+    /// Hold a minimal set of dynamic linking symbols
+    DYNSYM,
+
     /// The section contains an array of pointers to initialization functions
     /// Each pointer in the array is tabken as a parameterless procedure wit a void return.
     INITARRAY,
@@ -302,14 +545,62 @@ pub enum SHType {
 
     SYMtabSHNDX,
 
+    /// GNU hash table of the dynamic symbols, 0x6fff_fff6
+    GNUHASH,
+
     SPECOS(u32),
 
     SPECPROC(u32),
 
     SPECUSER(u32),
+
+    /// Reserved value of the generic range
+    Unknown(u32),
+}
+
+/// What the `link` and `info` of a section header refer to,
+/// which depends on the section type.
+///
+/// Section references are section header table indices.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkInfoMeaning<'a> {
+    /// Neither field is used
+    None,
+
+    /// SYMTAB, DYNSYM: `link` is the string table of symbol names,
+    /// `info` is one greater than the index of the last local symbol
+    SymbolTable { strtab: usize, first_nonlocal: u32 },
+
+    /// DYNAMIC: `link` is the string table used by the entries
+    StringTable(usize),
+
+    /// REL, RELA: `link` is the symbol table,
+    /// `info` is the section to which the relocations apply (if any)
+    Relocates {
+        symtab: usize,
+        target: Option<usize>,
+    },
+
+    /// HASH, GNU_HASH, SYMTAB_SHNDX, GNU_versym:
+    /// `link` is the symbol table to which it applies
+    SymbolsOf(usize),
+
+    /// GROUP: `link` is the symbol table,
+    /// `info` is the index of the signature symbol in it
+    Group {
+        symtab: usize,
+        signature: Option<&'a str>,
+    },
+
+    /// GNU_verdef, GNU_verneed: `link` is the string table,
+    /// `info` is the number of entries
+    Versions { strtab: usize, count: u32 },
+
+    /// Type which isn't known, the raw values
+    Other { link: u32, info: u32 },
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum SHFlagBit {
     /// 0b1
     Write,
@@ -345,37 +636,73 @@ pub enum SHFlagBit {
     /// 0b100_0000_0000, = 0x400
     TLS,
 
-    /// Mask 0x0ff0_0000
+    /// The section holds compressed data (led by a `Chdr`)
+    /// 0b1000_0000_0000, = 0x800
+    Compressed,
+
+    /// Generic bits which aren't defined, as they are
+    /// Mask 0xffff_ffff_000f_f008, the high half only exists in ELF64
+    Unknown(u64),
+
+    /// Kept by the linker garbage collection (`--gc-sections`), GNU
+    /// 0x20_0000
+    GnuRetain,
+
+    /// Memory binding, GNU
+    /// 0x100_0000
+    GnuMbind,
+
+    /// Mask 0x0ff0_0000, the bits shifted right by 20
     OS(u8),
 
-    /// Mask 0xf000_0000
+    /// Mask 0x7000_0000, the bits shifted right by 28
     Proc(u8),
+
+    /// Not to be included in an executable or shared object, it's in the
+    /// processor-specific range but the same on every machine
+    /// 0x8000_0000
+    Exclude,
 }
 
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct SHFLAGS(Vec<SHFlagBit>);
 
-#[derive(Clone)]
-pub struct SHEntries(pub(crate) Vec<SHdrView>);
+/// The sections, and the index of each name (of the first section by it)
+/// for the lookup by name
+#[derive(Clone, PartialEq, Eq)]
+pub struct SHEntries(pub(crate) Vec<SHdrView>, HashMap<String, usize>);
 
 
 ////////////////////////////////////////////////////////////////////////////////
-//// Symbol Table
+// Symbol Table
 
-#[derive(Debug, Clone, Getters)]
+#[derive(Debug, Clone, PartialEq, Eq, Getters, Serialize)]
 #[getset(get = "pub")]
 pub struct SymView {
+    /// Empty in the lazy mode, see [`Elf::parse_lazy`]
     pub(crate) name: String,
+
+    /// Offset of the name in the string table
+    pub(crate) name_idx: u32,
+
+    /// Section index of the string table of the name, the `sh_link`
+    /// of the symbol table
+    pub(crate) strtab_idx: u32,
+
     pub(crate) bind: SymBinding,
     pub(crate) ty: SymType,
     pub(crate) visi: SymVisi,
     pub(crate) shndx: SID,
     pub(crate) value: SymValue,
-    pub(crate) size: u64
+    pub(crate) size: u64,
+
+    /// Version name of a dynamic symbol (`.gnu.version`), e.g.
+    /// `GLIBC_2.2.5`, `None` of the local, base and unversioned ones
+    pub(crate) version: Option<String>,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum SymBinding {
     /// 0
     Local,
@@ -393,7 +720,7 @@ pub enum SymBinding {
     Proc(u8),
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum SymType {
     /// 0, type is unspecified
     NoType,
@@ -425,7 +752,7 @@ pub enum SymType {
     Proc(u8),
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 #[repr(u8)]
 pub enum SymVisi {
     Default = 0,
@@ -434,30 +761,478 @@ pub enum SymVisi {
     Protected,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum SymValue {
     Alignment(u64),
     SectionOffset(u64),
-    VirAddr(Hex64)
+    VirAddr(Hex64),
+
+    /// Of an undefined (`SHN_UNDEF`) symbol with the value zero,
+    /// i.e. imported rather than located at the address zero
+    Undefined
 }
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Eq, Serialize)]
 pub struct SymTab(pub(crate) Vec<SymView>);
 
 
+////////////////////////////////////////////////////////////////////////////////
+// Relocation
+
+/// Relocation entry with explicit addend
+#[derive(Debug, Clone, PartialEq, Eq, CopyGetters, Serialize)]
+#[getset(get_copy = "pub")]
+pub struct RelaView {
+    pub(crate) offset: Hex64,
+
+    /// Index in the symbol table which the section `link` refers to
+    pub(crate) sym: u32,
+
+    /// Machine specific relocation type
+    pub(crate) ty: u32,
+
+    /// Signed, typically negative for PC-relative relocations
+    pub(crate) addend: i64,
+}
+
+/// x86-64 relocation types (`R_X86_64_*`), as the `ty` of `RelaView`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelocX86_64 {
+    NONE,
+
+    /// 1, `R_X86_64_64`, direct 64 bit
+    ABS64,
+
+    /// 2, PC relative 32 bit signed
+    PC32,
+
+    /// 3, 32 bit GOT entry
+    GOT32,
+
+    /// 4, 32 bit PLT address
+    PLT32,
+
+    /// 5, Copy symbol at runtime
+    COPY,
+
+    /// 6, Create GOT entry
+    GLOBDAT,
+
+    /// 7, Create PLT entry
+    JUMPSLOT,
+
+    /// 8, Adjust by program base
+    RELATIVE,
+
+    /// 9, 32 bit signed PC relative offset to GOT
+    GOTPCREL,
+
+    /// 10, `R_X86_64_32`, direct 32 bit zero extended
+    ABS32,
+
+    /// 11, `R_X86_64_32S`, direct 32 bit sign extended
+    ABS32S,
+
+    /// 12, `R_X86_64_16`
+    ABS16,
+    PC16,
+
+    /// 14, `R_X86_64_8`
+    ABS8,
+    PC8,
+
+    /// 16, ID of module containing symbol
+    DTPMOD64,
+
+    /// 17, Offset in the TLS block
+    DTPOFF64,
+
+    /// 18, Offset in the initial TLS block
+    TPOFF64,
+
+    TLSGD,
+    TLSLD,
+    DTPOFF32,
+    GOTTPOFF,
+    TPOFF32,
+    PC64,
+    GOTOFF64,
+    GOTPC32,
+    GOT64,
+    GOTPCREL64,
+    GOTPC64,
+    GOTPLT64,
+    PLTOFF64,
+    SIZE32,
+    SIZE64,
+    GOTPC32TLSDESC,
+    TLSDESCCALL,
+    TLSDESC,
+
+    /// 37, Adjust by the result of an ifunc resolver
+    IRELATIVE,
+
+    RELATIVE64,
+
+    /// 41, Relaxable `GOTPCREL`
+    GOTPCRELX,
+
+    /// 42, Relaxable `GOTPCREL` with a REX prefix
+    REXGOTPCRELX,
+
+    Unknown(u32),
+}
+
+/// PLT stub of an imported function, see [`Elf::plt_entries`]
+#[derive(Debug, Clone, PartialEq, Eq, Getters, Serialize)]
+#[getset(get = "pub")]
+pub struct PltEntry {
+    /// Index of the relocation in `.rela.plt`
+    pub(crate) index: usize,
+
+    /// Address of the stub which the calls go to
+    pub(crate) addr: Hex64,
+
+    /// Address of the GOT slot which the stub jumps through
+    pub(crate) got: Hex64,
+
+    pub(crate) symbol: String,
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Dynamic Section
+
+/// https://refspecs.linuxfoundation.org/elf/gabi4+/ch5.dynamic.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum DynTag {
+    /// End of the dynamic array
+    NULL,
+
+    /// String table offset of a needed library name
+    NEEDED,
+    PLTRELSZ,
+    PLTGOT,
+    HASH,
+    STRTAB,
+    SYMTAB,
+    RELA,
+    RELASZ,
+    RELAENT,
+    STRSZ,
+    SYMENT,
+    INIT,
+    FINI,
+
+    /// String table offset of the name of this shared object
+    SONAME,
+
+    /// String table offset of the search path (deprecated by RUNPATH)
+    RPATH,
+    SYMBOLIC,
+    REL,
+    RELSZ,
+    RELENT,
+    PLTREL,
+    DEBUG,
+    TEXTREL,
+    JMPREL,
+    BINDNOW,
+    INITARRAY,
+    FINIARRAY,
+    INITARRAYSZ,
+    FINIARRAYSZ,
+
+    /// String table offset of the search path
+    RUNPATH,
+    FLAGS,
+    PREINITARRAY,
+    PREINITARRAYSZ,
+
+    /// 0x6fff_fef5
+    GNUHASH,
+
+    /// 0x6fff_fff0
+    VERSYM,
+
+    /// 0x6fff_fff9
+    RELACOUNT,
+
+    /// 0x6fff_fffb
+    FLAGS1,
+
+    /// 0x6fff_fffc
+    VERDEF,
+
+    /// 0x6fff_fffd
+    VERDEFNUM,
+
+    /// 0x6fff_fffe
+    VERNEED,
+
+    /// 0x6fff_ffff
+    VERNEEDNUM,
+
+    Other(i64),
+}
+
+/// Entry of the dynamic section
+#[derive(Debug, Clone, PartialEq, Eq, Getters, CopyGetters, Serialize)]
+pub struct DynEntry {
+    #[getset(get_copy = "pub")]
+    pub(crate) tag: DynTag,
+
+    #[getset(get_copy = "pub")]
+    pub(crate) val: u64,
+
+    /// Resolved in `.dynstr` for the string valued tags
+    /// (NEEDED, SONAME, RPATH, RUNPATH)
+    #[getset(get = "pub")]
+    pub(crate) name: Option<String>,
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Exception Frame
+
+/// `.eh_frame_hdr`, the binary search table of the FDEs in `.eh_frame`
+/// for the unwinders, see [`Elf::eh_frame_hdr`]
+#[derive(Debug, Clone, PartialEq, Eq, Getters, Serialize)]
+#[getset(get = "pub")]
+pub struct EhFrameHdr {
+    /// 1
+    pub(crate) version: u8,
+
+    /// DWARF pointer encodings (`DW_EH_PE_*`) of `eh_frame_ptr`,
+    /// `fde_count` and the table
+    pub(crate) eh_frame_ptr_enc: u8,
+    pub(crate) fde_count_enc: u8,
+    pub(crate) table_enc: u8,
+
+    /// Address of `.eh_frame`
+    pub(crate) eh_frame_ptr: Hex64,
+
+    /// `(initial_location, fde_address)` sorted by the initial location,
+    /// both are resolved into the virtual addresses
+    pub(crate) table: Vec<(Hex64, Hex64)>,
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Note
+
+/// Note entry of a NOTE section or segment
+#[derive(Debug, Clone, PartialEq, Eq, Getters, Serialize)]
+#[getset(get = "pub")]
+pub struct NoteView {
+    /// Owner, e.g. "GNU", "Go", "CORE"
+    pub(crate) name: String,
+
+    /// Owner-specific note type
+    pub(crate) ty: u32,
+
+    pub(crate) desc: Vec<u8>,
+}
+
+/// Hardware security features of the GNU property note, see
+/// [`Elf::gnu_properties`]
+#[derive(
+    Default, Debug, Clone, Copy, PartialEq, Eq, CopyGetters, Serialize,
+)]
+#[getset(get_copy = "pub")]
+pub struct GnuProperties {
+    /// Intel CET shadow stack (`GNU_PROPERTY_X86_FEATURE_1_SHSTK`)
+    pub(crate) shadow_stack: bool,
+
+    /// Intel CET indirect branch tracking
+    /// (`GNU_PROPERTY_X86_FEATURE_1_IBT`)
+    pub(crate) ibt: bool,
+
+    /// Arm branch target identification
+    /// (`GNU_PROPERTY_AARCH64_FEATURE_1_BTI`)
+    pub(crate) bti: bool,
+
+    /// Arm pointer authentication (`GNU_PROPERTY_AARCH64_FEATURE_1_PAC`)
+    pub(crate) pac: bool,
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Core Dump
+
+/// Crashed process recorded in a core dump, see [`Elf::core_info`]
+#[derive(Debug, Clone, PartialEq, Eq, Getters, Serialize)]
+#[getset(get = "pub")]
+pub struct CoreInfo {
+    pub(crate) pid: u32,
+
+    /// Signal which terminated the process (of the first thread)
+    pub(crate) signal: u16,
+
+    /// Executable name, `pr_fname` (truncated to 15 bytes)
+    pub(crate) command: String,
+
+    /// Command line, `pr_psargs` (the arguments joined by spaces,
+    /// truncated to 79 bytes)
+    pub(crate) args: String,
+
+    /// Of each `NT_PRSTATUS`, the thread which received the signal first
+    pub(crate) threads: Vec<CoreThread>,
+}
+
+/// Thread state of a `NT_PRSTATUS` note
+#[derive(Debug, Clone, PartialEq, Eq, Getters, Serialize)]
+#[getset(get = "pub")]
+pub struct CoreThread {
+    pub(crate) pid: u32,
+
+    /// `pr_cursig`, 0 of the threads other than the signaled one
+    pub(crate) signal: u16,
+
+    /// General purpose registers as named by the kernel's `user_regs_struct`
+    /// in its order, empty of an unsupported machine
+    pub(crate) registers: Vec<(&'static str, u64)>,
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Symbol Versioning
+
+/// Version definition from `.gnu.version_d`
+#[derive(Debug, Clone, PartialEq, Eq, Getters, Serialize)]
+#[getset(get = "pub")]
+pub struct VerDefView {
+    pub(crate) ndx: u16,
+    pub(crate) flags: u16,
+    pub(crate) name: String,
+    pub(crate) parents: Vec<String>,
+}
+
+/// Version requirements of one needed file from `.gnu.version_r`
+#[derive(Debug, Clone, PartialEq, Eq, Getters, Serialize)]
+#[getset(get = "pub")]
+pub struct VerNeedView {
+    pub(crate) file: String,
+    pub(crate) aux: Vec<VerNeedAuxView>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Getters, Serialize)]
+#[getset(get = "pub")]
+pub struct VerNeedAuxView {
+    pub(crate) name: String,
+    pub(crate) flags: u16,
+
+    /// Version index, referred by `.gnu.version`
+    pub(crate) other: u16,
+}
+
+/// Resolved version of a dynamic symbol
+#[derive(Debug, Clone, PartialEq, Eq, Getters, CopyGetters)]
+pub struct VersionInfo {
+    #[getset(get = "pub")]
+    pub(crate) name: String,
+
+    /// Hidden version (`sym@VER`), only reachable by explicit version
+    #[getset(get_copy = "pub")]
+    pub(crate) hidden: bool,
+
+    /// Default version of a defined symbol (`sym@@VER`)
+    #[getset(get_copy = "pub")]
+    pub(crate) is_default: bool,
+}
+
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Hash Table
+
+/// GNU hash table (`.gnu.hash`), only the dynamic symbols from `symoffset`
+/// are hashed
+#[derive(Debug, Clone, PartialEq, Eq, Getters, CopyGetters, Serialize)]
+pub struct GnuHashTable {
+    #[getset(get_copy = "pub")]
+    pub(crate) symoffset: u32,
+
+    #[getset(get_copy = "pub")]
+    pub(crate) bloom_shift: u32,
+
+    /// Bits of a bloom filter word, 64 or 32 by the class
+    #[getset(get_copy = "pub")]
+    pub(crate) bloom_word_bits: u32,
+
+    /// Words of the bloom filter, widened to u64
+    #[getset(get = "pub")]
+    pub(crate) bloom: Vec<u64>,
+
+    /// The first symbol index of each bucket, 0 of an empty one
+    #[getset(get = "pub")]
+    pub(crate) buckets: Vec<u32>,
+
+    /// Hash of each symbol from `symoffset`, with the lowest bit set
+    /// at the end of a bucket
+    #[getset(get = "pub")]
+    pub(crate) chain: Vec<u32>,
+}
+
+/// SysV hash table (`.hash`), all the dynamic symbols are hashed
+#[derive(Debug, Clone, PartialEq, Eq, Getters, Serialize)]
+#[getset(get = "pub")]
+pub struct SysvHashTable {
+    /// The first symbol index of each bucket, 0 (`STN_UNDEF`) of an empty
+    /// one
+    pub(crate) buckets: Vec<u32>,
+
+    /// The next symbol index in the same bucket of each symbol, 0 at the
+    /// end of a bucket
+    pub(crate) chain: Vec<u32>,
+}
+
+
 
 ////////////////////////////////////////////////////////////////////////////////
-//// Debug Implements
+// Debug Implements
 
+/// Zero-padded to the full 16 digits of a 64-bit value, or to the width
+/// of the format (`{:8?}` of `0x00401000`), so the addresses align
 impl Debug for Hex64 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "0x{:04x}", &self.0)
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let width = f.width().unwrap_or(16);
+
+        write!(f, "0x{:0width$x}", &self.0)
+    }
+}
+
+impl Display for Hex64 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Hex digits with or without the `0x` (`0X`) prefix
+impl FromStr for Hex64 {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s
+            .strip_prefix("0x")
+            .or_else(|| s.strip_prefix("0X"))
+            .unwrap_or(s);
+
+        u64::from_str_radix(digits, 16).map(Self)
+    }
+}
+
+impl TryFrom<&str> for Hex64 {
+    type Error = ParseIntError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
     }
 }
 
 
 impl Debug for MagicNums {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
             "{:0x}, {:0x}, {:0x}, {:0x}",
@@ -466,6 +1241,41 @@ impl Debug for MagicNums {
     }
 }
 
+/// As the hex string of the `Debug`, e.g. `"0x0000000000001195"`
+impl Serialize for Hex64 {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{:?}", self))
+    }
+}
+
+/// As the sections only, the index is rebuilt by the name
+impl Serialize for SHEntries {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct("SHEntries", &self.0)
+    }
+}
+
+/// As the hex bytes of `readelf -h`, i.e. `"7f 45 4c 46"`
+impl Serialize for MagicNums {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let [a, b, c, d] = self.0;
+
+        serializer.collect_str(&format_args!(
+            "{:02x} {:02x} {:02x} {:02x}",
+            a, b, c, d
+        ))
+    }
+}
+
 impl From<u32> for PFLAGS {
     fn from(val: u32) -> Self {
         let mut flags = vec![];
@@ -482,8 +1292,8 @@ impl From<u32> for PFLAGS {
             flags.push(PFlagBit::R)
         }
 
-        let os_spec = (val & 0x0ff0_0000) as u8;
-        let proc_spec = (val & 0xf000_0000) as u8;
+        let os_spec = ((val & 0x0ff0_0000) >> 20) as u8;
+        let proc_spec = ((val & 0xf000_0000) >> 28) as u8;
 
         if os_spec > 0 {
             flags.push(PFlagBit::OS(os_spec))
@@ -497,9 +1307,24 @@ impl From<u32> for PFLAGS {
     }
 }
 
+impl PFLAGS {
+    pub fn has(&self, bit: PFlagBit) -> bool {
+        self.0.contains(&bit)
+    }
+
+    /// `R`, `W` and `E` in the fixed columns as the `Flg` of `readelf -l`,
+    /// e.g. `R E`, whatever the order of the bits
+    pub fn letters(&self) -> String {
+        [(PFlagBit::R, 'R'), (PFlagBit::W, 'W'), (PFlagBit::X, 'E')]
+            .into_iter()
+            .map(|(bit, letter)| if self.has(bit) { letter } else { ' ' })
+            .collect()
+    }
+}
+
 impl Debug for E64Phdr {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let ptype: PhType = unsafe { std::mem::transmute(self.ty()) };
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let ptype = PhType::from(self.ty());
         let flags = PFLAGS::from(self.flags());
 
         f.debug_struct("E64Phdr")
@@ -516,9 +1341,9 @@ impl Debug for E64Phdr {
 }
 
 impl Debug for E64PhEntries {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if let Some(ref entries) = self.0 {
-            for (i, entry) in entries.into_iter().enumerate() {
+            for (i, entry) in entries.iter().enumerate() {
                 writeln!(f, "{}: {:?}", i, entry)?;
             }
             Ok(())
@@ -528,66 +1353,387 @@ impl Debug for E64PhEntries {
     }
 }
 
-impl From<u32> for SHType {
+impl From<u32> for PhType {
     fn from(val: u32) -> Self {
-        if val >= 0x6000_0000 && val <= 0x6fff_ffff {
-            SHType::SPECOS(val)
-        } else if val >= 0x7000_0000 && val <= 0x7fff_ffff {
+        match val {
+            0 => Self::NULL,
+            1 => Self::LOAD,
+            2 => Self::DYNAMIC,
+            3 => Self::INTERP,
+            4 => Self::NOTE,
+            5 => Self::SHLIB,
+            6 => Self::PHDR,
+            7 => Self::TLS,
+            0x6474_e550 => Self::GNUEHFRAME,
+            0x6474_e551 => Self::GNUSTACK,
+            0x6474_e552 => Self::GNURELRO,
+            0x6000_0000..=0x6fff_ffff => Self::SPECOS(val),
+            0x7000_0000..=0x7fff_ffff => Self::SPECPROC(val),
+            _ => Self::Unknown(val),
+        }
+    }
+}
+
+impl From<PhType> for u32 {
+    fn from(ty: PhType) -> Self {
+        match ty {
+            PhType::NULL => 0,
+            PhType::LOAD => 1,
+            PhType::DYNAMIC => 2,
+            PhType::INTERP => 3,
+            PhType::NOTE => 4,
+            PhType::SHLIB => 5,
+            PhType::PHDR => 6,
+            PhType::TLS => 7,
+            PhType::LOOS => 0x6000_0000,
+            PhType::GNUEHFRAME => 0x6474_e550,
+            PhType::GNUSTACK => 0x6474_e551,
+            PhType::GNURELRO => 0x6474_e552,
+            PhType::HIOS => 0x6fff_ffff,
+            PhType::LOPROC => 0x7000_0000,
+            PhType::HOPROC => 0x7fff_ffff,
+            PhType::SPECOS(x) | PhType::SPECPROC(x) | PhType::Unknown(x) => x,
+        }
+    }
+}
+
+impl From<E64Phdr> for PHdrView {
+    fn from(phdr: E64Phdr) -> Self {
+        Self {
+            ty: PhType::from(phdr.ty()),
+            flags: PFLAGS::from(phdr.flags()),
+            offset: phdr.offset(),
+            vaddr: Hex64(phdr.vaddr()),
+            paddr: Hex64(phdr.paddr()),
+            filesz: phdr.filesz(),
+            memsz: phdr.memsz(),
+            align: phdr.align(),
+        }
+    }
+}
+
+impl From<E32Phdr> for PHdrView {
+    fn from(phdr: E32Phdr) -> Self {
+        Self {
+            ty: PhType::from(phdr.ty()),
+            flags: PFLAGS::from(phdr.flags()),
+            offset: phdr.offset().into(),
+            vaddr: Hex64(phdr.vaddr().into()),
+            paddr: Hex64(phdr.paddr().into()),
+            filesz: phdr.filesz().into(),
+            memsz: phdr.memsz().into(),
+            align: phdr.align().into(),
+        }
+    }
+}
+
+impl From<E64Rela> for RelaView {
+    fn from(rela: E64Rela) -> Self {
+        Self {
+            offset: Hex64(rela.offset()),
+            sym: (rela.info() >> 32) as u32,
+            ty: rela.info() as u32,
+            addend: rela.addend(),
+        }
+    }
+}
+
+impl From<u32> for RelocX86_64 {
+    fn from(val: u32) -> Self {
+        match val {
+            0 => Self::NONE,
+            1 => Self::ABS64,
+            2 => Self::PC32,
+            3 => Self::GOT32,
+            4 => Self::PLT32,
+            5 => Self::COPY,
+            6 => Self::GLOBDAT,
+            7 => Self::JUMPSLOT,
+            8 => Self::RELATIVE,
+            9 => Self::GOTPCREL,
+            10 => Self::ABS32,
+            11 => Self::ABS32S,
+            12 => Self::ABS16,
+            13 => Self::PC16,
+            14 => Self::ABS8,
+            15 => Self::PC8,
+            16 => Self::DTPMOD64,
+            17 => Self::DTPOFF64,
+            18 => Self::TPOFF64,
+            19 => Self::TLSGD,
+            20 => Self::TLSLD,
+            21 => Self::DTPOFF32,
+            22 => Self::GOTTPOFF,
+            23 => Self::TPOFF32,
+            24 => Self::PC64,
+            25 => Self::GOTOFF64,
+            26 => Self::GOTPC32,
+            27 => Self::GOT64,
+            28 => Self::GOTPCREL64,
+            29 => Self::GOTPC64,
+            30 => Self::GOTPLT64,
+            31 => Self::PLTOFF64,
+            32 => Self::SIZE32,
+            33 => Self::SIZE64,
+            34 => Self::GOTPC32TLSDESC,
+            35 => Self::TLSDESCCALL,
+            36 => Self::TLSDESC,
+            37 => Self::IRELATIVE,
+            38 => Self::RELATIVE64,
+            41 => Self::GOTPCRELX,
+            42 => Self::REXGOTPCRELX,
+            x => Self::Unknown(x),
+        }
+    }
+}
+
+impl RelocX86_64 {
+    /// Name as `readelf -r`, `None` for an unknown type
+    pub fn name(&self) -> Option<&'static str> {
+        Some(match self {
+            Self::NONE => "R_X86_64_NONE",
+            Self::ABS64 => "R_X86_64_64",
+            Self::PC32 => "R_X86_64_PC32",
+            Self::GOT32 => "R_X86_64_GOT32",
+            Self::PLT32 => "R_X86_64_PLT32",
+            Self::COPY => "R_X86_64_COPY",
+            Self::GLOBDAT => "R_X86_64_GLOB_DAT",
+            Self::JUMPSLOT => "R_X86_64_JUMP_SLOT",
+            Self::RELATIVE => "R_X86_64_RELATIVE",
+            Self::GOTPCREL => "R_X86_64_GOTPCREL",
+            Self::ABS32 => "R_X86_64_32",
+            Self::ABS32S => "R_X86_64_32S",
+            Self::ABS16 => "R_X86_64_16",
+            Self::PC16 => "R_X86_64_PC16",
+            Self::ABS8 => "R_X86_64_8",
+            Self::PC8 => "R_X86_64_PC8",
+            Self::DTPMOD64 => "R_X86_64_DTPMOD64",
+            Self::DTPOFF64 => "R_X86_64_DTPOFF64",
+            Self::TPOFF64 => "R_X86_64_TPOFF64",
+            Self::TLSGD => "R_X86_64_TLSGD",
+            Self::TLSLD => "R_X86_64_TLSLD",
+            Self::DTPOFF32 => "R_X86_64_DTPOFF32",
+            Self::GOTTPOFF => "R_X86_64_GOTTPOFF",
+            Self::TPOFF32 => "R_X86_64_TPOFF32",
+            Self::PC64 => "R_X86_64_PC64",
+            Self::GOTOFF64 => "R_X86_64_GOTOFF64",
+            Self::GOTPC32 => "R_X86_64_GOTPC32",
+            Self::GOT64 => "R_X86_64_GOT64",
+            Self::GOTPCREL64 => "R_X86_64_GOTPCREL64",
+            Self::GOTPC64 => "R_X86_64_GOTPC64",
+            Self::GOTPLT64 => "R_X86_64_GOTPLT64",
+            Self::PLTOFF64 => "R_X86_64_PLTOFF64",
+            Self::SIZE32 => "R_X86_64_SIZE32",
+            Self::SIZE64 => "R_X86_64_SIZE64",
+            Self::GOTPC32TLSDESC => "R_X86_64_GOTPC32_TLSDESC",
+            Self::TLSDESCCALL => "R_X86_64_TLSDESC_CALL",
+            Self::TLSDESC => "R_X86_64_TLSDESC",
+            Self::IRELATIVE => "R_X86_64_IRELATIVE",
+            Self::RELATIVE64 => "R_X86_64_RELATIVE64",
+            Self::GOTPCRELX => "R_X86_64_GOTPCRELX",
+            Self::REXGOTPCRELX => "R_X86_64_REX_GOTPCRELX",
+            Self::Unknown(_) => return None,
+        })
+    }
+}
+
+impl RelaView {
+    /// Name of the relocation type by the machine of `elf`,
+    /// `None` for an unknown type or machine
+    pub fn reloc_type_name(&self, elf: &Elf) -> Option<&'static str> {
+        match elf.ehdr().machine() {
+            EMachine::X86_64 => RelocX86_64::from(self.ty).name(),
+            _ => None,
+        }
+    }
+}
+
+impl From<i64> for DynTag {
+    fn from(val: i64) -> Self {
+        match val {
+            0 => Self::NULL,
+            1 => Self::NEEDED,
+            2 => Self::PLTRELSZ,
+            3 => Self::PLTGOT,
+            4 => Self::HASH,
+            5 => Self::STRTAB,
+            6 => Self::SYMTAB,
+            7 => Self::RELA,
+            8 => Self::RELASZ,
+            9 => Self::RELAENT,
+            10 => Self::STRSZ,
+            11 => Self::SYMENT,
+            12 => Self::INIT,
+            13 => Self::FINI,
+            14 => Self::SONAME,
+            15 => Self::RPATH,
+            16 => Self::SYMBOLIC,
+            17 => Self::REL,
+            18 => Self::RELSZ,
+            19 => Self::RELENT,
+            20 => Self::PLTREL,
+            21 => Self::DEBUG,
+            22 => Self::TEXTREL,
+            23 => Self::JMPREL,
+            24 => Self::BINDNOW,
+            25 => Self::INITARRAY,
+            26 => Self::FINIARRAY,
+            27 => Self::INITARRAYSZ,
+            28 => Self::FINIARRAYSZ,
+            29 => Self::RUNPATH,
+            30 => Self::FLAGS,
+            32 => Self::PREINITARRAY,
+            33 => Self::PREINITARRAYSZ,
+            0x6fff_fef5 => Self::GNUHASH,
+            0x6fff_fff0 => Self::VERSYM,
+            0x6fff_fff9 => Self::RELACOUNT,
+            0x6fff_fffb => Self::FLAGS1,
+            0x6fff_fffc => Self::VERDEF,
+            0x6fff_fffd => Self::VERDEFNUM,
+            0x6fff_fffe => Self::VERNEED,
+            0x6fff_ffff => Self::VERNEEDNUM,
+            x => Self::Other(x),
+        }
+    }
+}
+
+impl DynEntry {
+    pub(crate) fn new(dyn_: E64Dyn, dynstr: &StrTab) -> Self {
+        let tag = DynTag::from(dyn_.tag());
+        let val = dyn_.val();
+
+        let name = match tag {
+            DynTag::NEEDED
+            | DynTag::SONAME
+            | DynTag::RPATH
+            | DynTag::RUNPATH => dynstr.get(val as usize),
+            _ => None,
+        };
+
+        Self { tag, val, name }
+    }
+}
+
+impl From<SHType> for u32 {
+    fn from(ty: SHType) -> Self {
+        match ty {
+            SHType::NULL => 0,
+            SHType::PROGBITS => 1,
+            SHType::SYMtab => 2,
+            SHType::STRtab => 3,
+            SHType::RELA => 4,
+            SHType::HASH => 5,
+            SHType::DYNAMIC => 6,
+            SHType::NOTE => 7,
+            SHType::NOBITS => 8,
+            SHType::REL => 9,
+            SHType::SHLIB => 10,
+            SHType::DYNSYM => 11,
+            SHType::INITARRAY => 14,
+            SHType::FINIARRAY => 15,
+            SHType::PREINITARRAY => 16,
+            SHType::GROUP => 17,
+            SHType::SYMtabSHNDX => 18,
+            SHType::GNUHASH => 0x6fff_fff6,
+            SHType::SPECOS(x)
+            | SHType::SPECPROC(x)
+            | SHType::SPECUSER(x)
+            | SHType::Unknown(x) => x,
+        }
+    }
+}
+
+impl From<u32> for SHType {
+    fn from(val: u32) -> Self {
+        if val == 0x6fff_fff6 {
+            SHType::GNUHASH
+        } else if (0x6000_0000..=0x6fff_ffff).contains(&val) {
+            SHType::SPECOS(val)
+        } else if (0x7000_0000..=0x7fff_ffff).contains(&val) {
             SHType::SPECPROC(val)
         } else if val >= 0x8000_0000 {
             SHType::SPECUSER(val)
         } else {
-            unsafe { transmute::<u64, Self>(val as u64) }
+            match val {
+                0 => SHType::NULL,
+                1 => SHType::PROGBITS,
+                2 => SHType::SYMtab,
+                3 => SHType::STRtab,
+                4 => SHType::RELA,
+                5 => SHType::HASH,
+                6 => SHType::DYNAMIC,
+                7 => SHType::NOTE,
+                8 => SHType::NOBITS,
+                9 => SHType::REL,
+                10 => SHType::SHLIB,
+                11 => SHType::DYNSYM,
+                14 => SHType::INITARRAY,
+                15 => SHType::FINIARRAY,
+                16 => SHType::PREINITARRAY,
+                17 => SHType::GROUP,
+                18 => SHType::SYMtabSHNDX,
+                x => SHType::Unknown(x),
+            }
         }
     }
 }
 
-impl From<u32> for SHFLAGS {
-    fn from(val: u32) -> Self {
+impl From<u64> for SHFLAGS {
+    fn from(val: u64) -> Self {
         let mut flags = vec![];
 
-        if val & 0b1u32 > 0 {
+        if val & 0b1u64 > 0 {
             flags.push(SHFlagBit::Write);
         }
 
-        if val & 0b10u32 > 0 {
+        if val & 0b10u64 > 0 {
             flags.push(SHFlagBit::Alloc)
         }
 
-        if val & 0b100u32 > 0 {
+        if val & 0b100u64 > 0 {
             flags.push(SHFlagBit::ExecInstr)
         }
 
-        if val & 0b1_0000u32 > 0 {
+        if val & 0b1_0000u64 > 0 {
             flags.push(SHFlagBit::Merge)
         }
 
-        if val & 0b10_0000u32 > 0 {
+        if val & 0b10_0000u64 > 0 {
             flags.push(SHFlagBit::StringS)
         }
 
-        if val & 0b100_0000u32 > 0 {
+        if val & 0b100_0000u64 > 0 {
             flags.push(SHFlagBit::InfoLink)
         }
 
-        if val & 0b1000_0000u32 > 0 {
+        if val & 0b1000_0000u64 > 0 {
             flags.push(SHFlagBit::LinkOrder)
         }
 
-        if val & 0b1_0000_0000u32 > 0 {
+        if val & 0b1_0000_0000u64 > 0 {
             flags.push(SHFlagBit::OsNonconforming)
         }
 
-        if val & 0b10_0000_0000u32 > 0 {
+        if val & 0b10_0000_0000u64 > 0 {
             flags.push(SHFlagBit::Group)
         }
 
-        if val & 0b100_0000_0000u32 > 0 {
+        if val & 0b100_0000_0000u64 > 0 {
             flags.push(SHFlagBit::TLS)
         }
 
-        let os_spec = (val & 0x0ff0_0000) as u8;
-        let proc_spec = (val & 0xf000_0000) as u8;
+        if val & 0b1000_0000_0000u64 > 0 {
+            flags.push(SHFlagBit::Compressed)
+        }
+
+        let unknown = val & 0xffff_ffff_000f_f008;
+
+        if unknown > 0 {
+            flags.push(SHFlagBit::Unknown(unknown))
+        }
+
+        let os_spec = ((val & 0x0ff0_0000) >> 20) as u8;
+        let proc_spec = ((val & 0x7000_0000) >> 28) as u8;
 
         if os_spec > 0 {
             flags.push(SHFlagBit::OS(os_spec))
@@ -597,12 +1743,135 @@ impl From<u32> for SHFLAGS {
             flags.push(SHFlagBit::Proc(proc_spec));
         }
 
+        if val & 0x8000_0000 > 0 {
+            flags.push(SHFlagBit::Exclude);
+        }
+
         SHFLAGS(flags)
     }
 }
 
+impl SHFLAGS {
+    /// Decode with the known OS-specific flags of `osabi` named
+    pub fn from_osabi(val: u64, osabi: u8) -> Self {
+        const ELFOSABI_NONE: u8 = 0;
+        const ELFOSABI_GNU: u8 = 3;
+        const ELFOSABI_FREEBSD: u8 = 9;
+
+        const SHF_GNU_RETAIN: u64 = 0x20_0000;
+        const SHF_GNU_MBIND: u64 = 0x100_0000;
+        const OS_MASK: u64 = 0x0ff0_0000;
+
+        if !matches!(osabi, ELFOSABI_NONE | ELFOSABI_GNU | ELFOSABI_FREEBSD) {
+            return Self::from(val);
+        }
+
+        let mut flags = Self::from(val & !OS_MASK);
+        let mut os_flags = vec![];
+        let mut named = 0;
+
+        /* The same as readelf, retain isn't a flag of the plain SysV ABI */
+        if val & SHF_GNU_RETAIN > 0 && osabi != ELFOSABI_NONE {
+            os_flags.push(SHFlagBit::GnuRetain);
+            named |= SHF_GNU_RETAIN;
+        }
+
+        if val & SHF_GNU_MBIND > 0 {
+            os_flags.push(SHFlagBit::GnuMbind);
+            named |= SHF_GNU_MBIND;
+        }
+
+        let os_spec = val & OS_MASK & !named;
+
+        if os_spec > 0 {
+            os_flags.push(SHFlagBit::OS((os_spec >> 20) as u8));
+        }
+
+        /* Before the processor-specific ones, as `From<u64>` */
+        let pos = flags
+            .0
+            .iter()
+            .position(|bit| {
+                matches!(bit, SHFlagBit::Proc(_) | SHFlagBit::Exclude)
+            })
+            .unwrap_or(flags.0.len());
+        flags.0.splice(pos..pos, os_flags);
+
+        flags
+    }
+
+    pub fn has(&self, bit: SHFlagBit) -> bool {
+        self.0.contains(&bit)
+    }
+
+    /// Encode back to the raw `sh_flags`
+    pub fn bits(&self) -> u64 {
+        self.0.iter().fold(0, |acc, bit| acc | bit.bits())
+    }
+
+    /// The conventional (readelf) letter of each bit, in the bit order.
+    ///
+    /// Unlike `readelf_letters`, it doesn't depend on the machine and has
+    /// one letter per `SHFlagBit`, e.g. an `o` of all the unnamed OS bits.
+    pub fn letters(&self) -> String {
+        let mut bits = self.0.clone();
+        bits.sort_by_key(|bit| bit.bits().trailing_zeros());
+
+        bits.iter().map(ToString::to_string).collect()
+    }
+}
+
+impl SHFlagBit {
+    /// The bits in the raw `sh_flags`
+    pub fn bits(&self) -> u64 {
+        match *self {
+            Self::Write => 0x1,
+            Self::Alloc => 0x2,
+            Self::ExecInstr => 0x4,
+            Self::Merge => 0x10,
+            Self::StringS => 0x20,
+            Self::InfoLink => 0x40,
+            Self::LinkOrder => 0x80,
+            Self::OsNonconforming => 0x100,
+            Self::Group => 0x200,
+            Self::TLS => 0x400,
+            Self::Compressed => 0x800,
+            Self::Unknown(bits) => bits,
+            Self::GnuRetain => 0x20_0000,
+            Self::GnuMbind => 0x100_0000,
+            Self::OS(bits) => (bits as u64) << 20,
+            Self::Proc(bits) => (bits as u64) << 28,
+            Self::Exclude => 0x8000_0000,
+        }
+    }
+}
+
+impl Display for SHFlagBit {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Write => "W",
+            Self::Alloc => "A",
+            Self::ExecInstr => "X",
+            Self::Merge => "M",
+            Self::StringS => "S",
+            Self::InfoLink => "I",
+            Self::LinkOrder => "L",
+            Self::OsNonconforming => "O",
+            Self::Group => "G",
+            Self::TLS => "T",
+            Self::Compressed => "C",
+            Self::Unknown(_) => "x",
+            Self::GnuRetain => "R",
+            Self::GnuMbind => "D",
+            Self::OS(_) => "o",
+            Self::Proc(_) => "p",
+            Self::Exclude => "E",
+        })
+    }
+}
+
 impl Debug for SHEntries {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if self.0.is_empty() {
             return write!(f, "None");
         }
@@ -617,7 +1886,7 @@ impl Debug for SHEntries {
 }
 
 impl Debug for StrTab {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         writeln!(f)?;
 
         for (i, s) in self.str_vec().into_iter().enumerate() {
@@ -635,9 +1904,9 @@ impl From<u16> for SID {
             0xfff2 => SID::Common,
             0xffff => SID::XIndex,
             x => {
-                if x >= 0xff00 && x <= 0xff1f {
+                if (0xff00..=0xff1f).contains(&x) {
                     SID::Proc(x)
-                } else if x >= 0xff20 && x <= 0xff3f {
+                } else if (0xff20..=0xff3f).contains(&x) {
                     SID::OS(x)
                 } else {
                     SID::Normal(x)
@@ -647,9 +1916,9 @@ impl From<u16> for SID {
     }
 }
 
-impl Into<usize> for SID {
-    fn into(self) -> usize {
-        match self {
+impl From<SID> for usize {
+    fn from(sid: SID) -> Self {
+        match sid {
             SID::Undef => 0,
             SID::Proc(x) => x as usize,
             SID::OS(x) => x as usize,
@@ -662,41 +1931,575 @@ impl Into<usize> for SID {
 }
 
 
-impl Into<EHdrView> for E64Hdr {
-    fn into(self) -> EHdrView {
-        let ident = self.ident().into();
-        let ty: EType = unsafe { std::mem::transmute(self.ty()) };
-        let machine: EMachine = unsafe { std::mem::transmute(self.machine()) };
-        let section_str_tab_idx = self.sh_strtab_idx().into();
+impl From<u8> for OsAbi {
+    fn from(val: u8) -> Self {
+        match val {
+            0 => Self::SysV,
+            1 => Self::HPUX,
+            2 => Self::NetBSD,
+            3 => Self::Linux,
+            6 => Self::Solaris,
+            7 => Self::AIX,
+            8 => Self::IRIX,
+            9 => Self::FreeBSD,
+            10 => Self::TRU64,
+            11 => Self::Modesto,
+            12 => Self::OpenBSD,
+            64 => Self::ArmAeabi,
+            97 => Self::Arm,
+            255 => Self::Standalone,
+            x => Self::Other(x),
+        }
+    }
+}
+
+impl From<OsAbi> for u8 {
+    fn from(osabi: OsAbi) -> Self {
+        match osabi {
+            OsAbi::SysV => 0,
+            OsAbi::HPUX => 1,
+            OsAbi::NetBSD => 2,
+            OsAbi::Linux => 3,
+            OsAbi::Solaris => 6,
+            OsAbi::AIX => 7,
+            OsAbi::IRIX => 8,
+            OsAbi::FreeBSD => 9,
+            OsAbi::TRU64 => 10,
+            OsAbi::Modesto => 11,
+            OsAbi::OpenBSD => 12,
+            OsAbi::ArmAeabi => 64,
+            OsAbi::Arm => 97,
+            OsAbi::Standalone => 255,
+            OsAbi::Other(x) => x,
+        }
+    }
+}
+
+impl From<u16> for EType {
+    fn from(val: u16) -> Self {
+        match val {
+            0 => Self::None,
+            1 => Self::REL,
+            2 => Self::EXEC,
+            3 => Self::DYN,
+            4 => Self::CORE,
+            0xfe00 => Self::LOOS,
+            0xfeff => Self::HIOS,
+            0xff00 => Self::LOPROC,
+            0xffff => Self::HIPROC,
+            x => Self::Unknown(x),
+        }
+    }
+}
+
+impl From<u16> for EMachine {
+    fn from(val: u16) -> Self {
+        match val {
+            0 => Self::None,
+            2 => Self::SPARC,
+            3 => Self::_386,
+            7 => Self::_860,
+            8 => Self::MIPS,
+            19 => Self::_960,
+            20 => Self::PPC,
+            21 => Self::PPC64,
+            22 => Self::S390,
+            40 => Self::ARM,
+            43 => Self::SPARCV9,
+            50 => Self::IA64,
+            51 => Self::MIPSX,
+            62 => Self::X86_64,
+            91 => Self::PJ,
+            183 => Self::AARCH64,
+            243 => Self::RISCV,
+            247 => Self::BPF,
+            258 => Self::LOONGARCH,
+            x => Self::Unknown(x),
+        }
+    }
+}
+
+impl From<EType> for u16 {
+    fn from(ty: EType) -> Self {
+        match ty {
+            EType::None => 0,
+            EType::REL => 1,
+            EType::EXEC => 2,
+            EType::DYN => 3,
+            EType::CORE => 4,
+            EType::LOOS => 0xfe00,
+            EType::HIOS => 0xfeff,
+            EType::LOPROC => 0xff00,
+            EType::HIPROC => 0xffff,
+            EType::Unknown(x) => x,
+        }
+    }
+}
+
+impl From<EMachine> for u16 {
+    fn from(machine: EMachine) -> Self {
+        match machine {
+            EMachine::None => 0,
+            EMachine::SPARC => 2,
+            EMachine::_386 => 3,
+            EMachine::_860 => 7,
+            EMachine::MIPS => 8,
+            EMachine::_960 => 19,
+            EMachine::PPC => 20,
+            EMachine::PPC64 => 21,
+            EMachine::S390 => 22,
+            EMachine::ARM => 40,
+            EMachine::SPARCV9 => 43,
+            EMachine::IA64 => 50,
+            EMachine::MIPSX => 51,
+            EMachine::X86_64 => 62,
+            EMachine::PJ => 91,
+            EMachine::AARCH64 => 183,
+            EMachine::RISCV => 243,
+            EMachine::BPF => 247,
+            EMachine::LOONGARCH => 258,
+            EMachine::Unknown(x) => x,
+        }
+    }
+}
+
+impl From<E64Hdr> for EHdrView {
+    fn from(hdr: E64Hdr) -> Self {
+        let ident = hdr.ident().into();
+        let ty = EType::from(hdr.ty());
+        let machine = EMachine::from(hdr.machine());
+        let section_str_tab_idx = hdr.sh_strtab_idx().into();
+
+        EHdrView {
+            ident,
+            ty,
+            machine,
+            version: hdr.version(),
+            entry: Hex64(hdr.entry()),
+            prog_hdr_offset: Hex64(hdr.phoff()),
+            section_hdr_offset: Hex64(hdr.shoff()),
+            flags: hdr.flags(),
+            elf_hdr_sz: hdr.ehsize(),
+            prog_hdr_tab_ent_sz: hdr.ph_tab_entry_size(),
+            prog_hdr_tab_ent_num: hdr.ph_tab_entry_num(),
+            section_hdr_ent_sz: hdr.sh_tab_entry_size(),
+            section_hdr_ent_num: hdr.sh_tab_entry_num(),
+            section_str_tab_idx,
+        }
+    }
+}
+
+impl From<E32Hdr> for EHdrView {
+    fn from(hdr: E32Hdr) -> Self {
+        let ident = hdr.ident().into();
+        let ty = EType::from(hdr.ty());
+        let machine = EMachine::from(hdr.machine());
+        let section_str_tab_idx = hdr.sh_strtab_idx().into();
 
         EHdrView {
             ident,
             ty,
             machine,
-            version: self.version(),
-            entry: Hex64(self.entry()),
-            prog_hdr_offset: Hex64(self.phoff()),
-            section_hdr_offset: Hex64(self.shoff()),
-            flags: self.flags(),
-            elf_hdr_sz: self.ehsize(),
-            prog_hdr_tab_ent_sz: self.ph_tab_entry_size(),
-            prog_hdr_tab_ent_num: self.ph_tab_entry_num(),
-            section_hdr_ent_sz: self.sh_tab_entry_size(),
-            section_hdr_ent_num: self.sh_tab_entry_num(),
+            version: hdr.version(),
+            entry: Hex64(hdr.entry().into()),
+            prog_hdr_offset: Hex64(hdr.phoff().into()),
+            section_hdr_offset: Hex64(hdr.shoff().into()),
+            flags: hdr.flags(),
+            elf_hdr_sz: hdr.ehsize(),
+            prog_hdr_tab_ent_sz: hdr.ph_tab_entry_size(),
+            prog_hdr_tab_ent_num: hdr.ph_tab_entry_num(),
+            section_hdr_ent_sz: hdr.sh_tab_entry_size(),
+            section_hdr_ent_num: hdr.sh_tab_entry_num(),
             section_str_tab_idx,
         }
     }
 }
 
+impl Hex64 {
+    pub const fn new(val: u64) -> Self {
+        Self(val)
+    }
+
+    pub const fn value(self) -> u64 {
+        self.0
+    }
+}
+
+/// Facts of the common machines by their psABI and the default of the
+/// GNU toolchain, `None` for the others
+impl EMachine {
+    /// Size in bytes of a pointer, `None` of RISC-V (either RV32 or RV64,
+    /// as the class tells)
+    pub fn pointer_size(&self) -> Option<u64> {
+        Some(match self {
+            Self::_386 | Self::ARM => 4,
+            Self::X86_64 | Self::AARCH64 | Self::PPC64 => 8,
+            _ => return None,
+        })
+    }
+
+    /// The machine is a 64-bit one (ELFCLASS64 expected), by the pointer
+    /// size
+    pub fn is_64bit_default(&self) -> Option<bool> {
+        self.pointer_size().map(|size| size == 8)
+    }
+
+    /// The maximum page size of `ld`, i.e. the segment alignment
+    pub fn default_page_size(&self) -> Option<u64> {
+        Some(match self {
+            Self::_386 | Self::X86_64 | Self::ARM | Self::RISCV => 0x1000,
+            Self::AARCH64 | Self::PPC64 => 0x1_0000,
+            _ => return None,
+        })
+    }
+
+    /// Size in bytes of a PLT stub (but the PLT0 header), `None` of PPC64
+    /// whose call stubs aren't in the PLT
+    pub fn plt_entry_size(&self) -> Option<u64> {
+        Some(match self {
+            Self::_386 | Self::X86_64 | Self::AARCH64 | Self::RISCV => 16,
+            Self::ARM => 12,
+            _ => return None,
+        })
+    }
+}
+
+impl EHdrView {
+    /// `flags` decoded by the machine, raw of an unknown one
+    pub fn decoded_flags(&self) -> EFlagsDecoded {
+        EFlagsDecoded::new(&self.machine, self.flags)
+    }
+}
+
+impl EFlagsDecoded {
+    pub fn new(machine: &EMachine, flags: u32) -> Self {
+        match machine {
+            EMachine::ARM => Self::Arm(ArmEFlags::from(flags)),
+            EMachine::MIPS => Self::Mips(MipsEFlags::from(flags)),
+            _ => Self::Raw(Hex64(flags as u64)),
+        }
+    }
+}
+
+impl From<u32> for ArmEFlags {
+    fn from(val: u32) -> Self {
+        let eabi_version = (val >> 24) as u8;
+
+        /* The float ABI bits are only defined since EABI 5 */
+        let float_abi = match val & 0x600 {
+            0x200 if eabi_version >= 5 => ArmFloatAbi::Soft,
+            0x400 if eabi_version >= 5 => ArmFloatAbi::Hard,
+            _ => ArmFloatAbi::Unspecified,
+        };
+
+        Self {
+            eabi_version,
+            float_abi,
+            be8: val & 0x80_0000 != 0,
+        }
+    }
+}
+
+impl From<u32> for MipsEFlags {
+    fn from(val: u32) -> Self {
+        let abi = match val & 0xf000 {
+            0x1000 => MipsAbi::O32,
+            0x2000 => MipsAbi::O64,
+            0x3000 => MipsAbi::EABI32,
+            0x4000 => MipsAbi::EABI64,
+            _ if val & 0x20 != 0 => MipsAbi::N32,
+            _ => MipsAbi::Unspecified,
+        };
+
+        Self {
+            abi,
+            arch: MipsArch::from((val >> 28) as u8),
+            noreorder: val & 0x1 != 0,
+            pic: val & 0x2 != 0,
+            cpic: val & 0x4 != 0,
+        }
+    }
+}
+
+impl From<u8> for MipsArch {
+    fn from(val: u8) -> Self {
+        match val {
+            0 => Self::Mips1,
+            1 => Self::Mips2,
+            2 => Self::Mips3,
+            3 => Self::Mips4,
+            4 => Self::Mips5,
+            5 => Self::Mips32,
+            6 => Self::Mips64,
+            7 => Self::Mips32R2,
+            8 => Self::Mips64R2,
+            9 => Self::Mips32R6,
+            10 => Self::Mips64R6,
+            x => Self::Unknown(x),
+        }
+    }
+}
+
+impl GnuHashTable {
+    /// The GNU hash function (`dl_new_hash`)
+    pub fn hash(name: &[u8]) -> u32 {
+        name.iter()
+            .fold(5381u32, |h, &c| h.wrapping_mul(33).wrapping_add(c as u32))
+    }
+
+    /// Index of the defined symbol `name` in `dynsym`, the table of this
+    /// hash table. As `ld.so`, the undefined ones on the chain are skipped.
+    pub fn lookup(&self, name: &str, dynsym: &SymTab) -> Option<usize> {
+        if self.bloom.is_empty() || self.buckets.is_empty() {
+            return None;
+        }
+
+        let hash = Self::hash(name.as_bytes());
+        let bits = self.bloom_word_bits;
+
+        /* Two bits of the hash, a missing one rules the name out */
+        let word = self.bloom[(hash / bits) as usize % self.bloom.len()];
+        let mask = (1u64 << (hash % bits))
+            | (1u64 << (hash.checked_shr(self.bloom_shift)? % bits));
+
+        if word & mask != mask {
+            return None;
+        }
+
+        let mut idx = self.buckets[hash as usize % self.buckets.len()];
+
+        if idx < self.symoffset {
+            return None;
+        }
+
+        loop {
+            let chained = *self.chain.get((idx - self.symoffset) as usize)?;
+
+            let sym = dynsym.get(idx as usize)?;
+
+            if hash | 1 == chained | 1
+                && sym.name == name
+                && !matches!(sym.shndx, SID::Undef)
+            {
+                return Some(idx as usize);
+            }
+
+            if chained & 1 != 0 {
+                return None;
+            }
+
+            idx += 1;
+        }
+    }
+}
+
 impl SHEntries {
+    pub(crate) fn new(entries: Vec<SHdrView>) -> Self {
+        let mut shentries = Self(entries, HashMap::new());
+        shentries.reindex();
+
+        shentries
+    }
+
+    /// Rebuild the name index, after the sections are renamed or removed
+    pub(crate) fn reindex(&mut self) {
+        self.1.clear();
+
+        for (i, entry) in self.0.iter().enumerate() {
+            self.1.entry(entry.name.clone()).or_insert(i);
+        }
+    }
+
     pub fn get(&self, name: &str) -> Option<&SHdrView> {
-        for entry in self.0.iter() {
-            if entry.name() == name {
-                return Some(entry);
-            }
+        self.index_of(name).map(|i| &self.0[i])
+    }
+
+    /// Section header table index of the section
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.1.get(name).copied()
+    }
+
+    /// Sections of the type `ty`, compared by the raw value, so e.g.
+    /// `SPECOS(0x6fff_fff6)` finds the `GNUHASH` ones as well
+    pub fn find_by_type(
+        &self,
+        ty: SHType,
+    ) -> impl Iterator<Item = &SHdrView> + '_ {
+        let raw = u32::from(ty);
+
+        self.0
+            .iter()
+            .filter(move |entry| u32::from(entry.ty) == raw)
+    }
+
+    pub fn first_by_type(&self, ty: SHType) -> Option<&SHdrView> {
+        self.find_by_type(ty).next()
+    }
+
+    /// Section at the section header table index
+    pub fn get_index(&self, i: usize) -> Option<&SHdrView> {
+        self.0.get(i)
+    }
+
+    /// Sections in the section header table order
+    pub fn iter(&self) -> core::slice::Iter<'_, SHdrView> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<'a> IntoIterator for &'a SHEntries {
+    type Item = &'a SHdrView;
+    type IntoIter = core::slice::Iter<'a, SHdrView>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl PHdrView {
+    /// Whether the section lies in this segment, by the strict rule
+    /// of the binutils' `ELF_SECTION_IN_SEGMENT_STRICT` (that of the
+    /// `readelf -l` section to segment mapping)
+    pub(crate) fn contains_section(&self, sh: &SHdrView) -> bool {
+        const PT_GNU_SFRAME: u32 = 0x6474_e554;
+
+        let ty = u32::from(self.ty);
+        let tls = sh.flags.has(SHFlagBit::TLS);
+        let alloc = sh.flags.has(SHFlagBit::Alloc);
+        let nobits = matches!(sh.ty, SHType::NOBITS);
+        let (off, addr) = (sh.offset.0, sh.addr.0);
+
+        /* The .tbss occupies no space out of the TLS segment */
+        if tls && nobits && !matches!(self.ty, PhType::TLS) {
+            return false;
         }
 
-        None
+        let tls_ok = if tls {
+            matches!(self.ty, PhType::TLS | PhType::LOAD | PhType::GNURELRO)
+        } else {
+            !matches!(self.ty, PhType::TLS | PhType::PHDR)
+        };
+
+        let alloc_ok = alloc
+            || !(matches!(
+                self.ty,
+                PhType::LOAD
+                    | PhType::DYNAMIC
+                    | PhType::GNUEHFRAME
+                    | PhType::GNUSTACK
+                    | PhType::GNURELRO
+            ) || ty == PT_GNU_SFRAME
+                || (0x6474_e555..=0x6474_f554).contains(&ty));
+
+        let offset_ok = nobits
+            || off >= self.offset
+                && off - self.offset <= self.filesz.wrapping_sub(1)
+                && (off - self.offset).wrapping_add(sh.size) <= self.filesz;
+
+        let vaddr_ok = !alloc
+            || addr >= self.vaddr.0
+                && addr - self.vaddr.0 <= self.memsz.wrapping_sub(1)
+                && (addr - self.vaddr.0).wrapping_add(sh.size) <= self.memsz;
+
+        /* No empty sections on the edges of the DYNAMIC or NOTE */
+        let edge_ok = !matches!(self.ty, PhType::DYNAMIC | PhType::NOTE)
+            || sh.size != 0
+            || self.memsz == 0
+            || (nobits
+                || off > self.offset && off - self.offset < self.filesz)
+                && (!alloc
+                    || addr > self.vaddr.0
+                        && addr - self.vaddr.0 < self.memsz);
+
+        tls_ok && alloc_ok && offset_ok && vaddr_ok && edge_ok
+    }
+}
+
+impl SHdrView {
+    /// All the bits of `flag` are set, so e.g. `OS(1)` is found in
+    /// `OS(3)` of the same `sh_flags` as well
+    pub fn has_flag(&self, flag: SHFlagBit) -> bool {
+        self.flags.bits() & flag.bits() == flag.bits()
+    }
+
+    /// `info` holds a section header table index
+    pub(crate) fn info_is_section_index(&self) -> bool {
+        matches!(self.ty, SHType::REL | SHType::RELA)
+            || self.flags.has(SHFlagBit::InfoLink)
+    }
+
+    /// Interpret `link` and `info` by the section type
+    pub fn interpret_link_info<'a>(
+        &self,
+        elf: &'a Elf,
+    ) -> LinkInfoMeaning<'a> {
+        let link = self.link as usize;
+        let info = self.info;
+
+        match self.ty {
+            SHType::SYMtab | SHType::DYNSYM => LinkInfoMeaning::SymbolTable {
+                strtab: link,
+                first_nonlocal: info,
+            },
+            SHType::DYNAMIC => LinkInfoMeaning::StringTable(link),
+            SHType::REL | SHType::RELA => LinkInfoMeaning::Relocates {
+                symtab: link,
+                target: if info == 0 { None } else { Some(info as usize) },
+            },
+            SHType::HASH | SHType::SYMtabSHNDX => {
+                LinkInfoMeaning::SymbolsOf(link)
+            }
+            SHType::GROUP => {
+                let symtab = match elf.shentries().0.get(link).map(|sh| sh.ty)
+                {
+                    Some(SHType::SYMtab) => Some(elf.symtab()),
+                    Some(SHType::DYNSYM) => Some(elf.dynsym()),
+                    _ => None,
+                };
+
+                LinkInfoMeaning::Group {
+                    symtab: link,
+                    signature: symtab
+                        .and_then(|symtab| symtab.0.get(info as usize))
+                        .map(|sym| sym.name.as_str()),
+                }
+            }
+            // GNU_versym
+            SHType::GNUHASH | SHType::SPECOS(0x6fff_ffff) => {
+                LinkInfoMeaning::SymbolsOf(link)
+            }
+            // GNU_verdef, GNU_verneed
+            SHType::SPECOS(0x6fff_fffd | 0x6fff_fffe) => {
+                LinkInfoMeaning::Versions {
+                    strtab: link,
+                    count: info,
+                }
+            }
+            SHType::NULL
+            | SHType::PROGBITS
+            | SHType::STRtab
+            | SHType::NOTE
+            | SHType::NOBITS
+            | SHType::INITARRAY
+            | SHType::FINIARRAY
+            | SHType::PREINITARRAY
+                if self.link == 0 && info == 0 =>
+            {
+                LinkInfoMeaning::None
+            }
+            _ => LinkInfoMeaning::Other {
+                link: self.link,
+                info,
+            },
+        }
     }
 }
 
@@ -709,7 +2512,7 @@ impl SymBinding {
             1 => Self::Global,
             2 => Self::Weak,
             x => {
-                if 10 <= x && x <= 12 {
+                if (10..=12).contains(&x) {
                     Self::OS(x)
                 } else {
                     Self::Proc(x)
@@ -717,6 +2520,18 @@ impl SymBinding {
             }
         }
     }
+
+    /// The binding in the high 4 bits of `st_info`
+    pub fn to_info_high(&self) -> u8 {
+        let val = match *self {
+            Self::Local => 0,
+            Self::Global => 1,
+            Self::Weak => 2,
+            Self::OS(x) | Self::Proc(x) => x,
+        };
+
+        val << 4
+    }
 }
 
 impl SymType {
@@ -732,7 +2547,7 @@ impl SymType {
             5 => Self::Common,
             6 => Self::TLS,
             x => {
-                if 10 <= x && x <= 12 {
+                if (10..=12).contains(&x) {
                     Self::OS(x)
                 } else {
                     Self::Proc(x)
@@ -740,18 +2555,194 @@ impl SymType {
             }
         }
     }
+
+    /// The type in the low 4 bits of `st_info`
+    pub fn to_info_low(&self) -> u8 {
+        let val = match *self {
+            Self::NoType => 0,
+            Self::Object => 1,
+            Self::Func => 2,
+            Self::Section => 3,
+            Self::File => 4,
+            Self::Common => 5,
+            Self::TLS => 6,
+            Self::OS(x) | Self::Proc(x) => x,
+        };
+
+        val & 0xf
+    }
+}
+
+impl SymView {
+    /// Name of the section where the symbol is defined
+    pub fn section_name<'a>(&self, elf: &'a Elf) -> Option<&'a str> {
+        match self.shndx {
+            SID::Normal(idx) => elf
+                .shentries()
+                .0
+                .get(idx as usize)
+                .map(|sh| sh.name.as_str()),
+            _ => None,
+        }
+    }
+
+    /// `st_info` recomposed from the binding and the type
+    pub fn raw_info(&self) -> u8 {
+        self.bind.to_info_high() | self.ty.to_info_low()
+    }
+
+    /// `st_other` recomposed from the visibility,
+    /// the other (unspecified) bits are zero
+    pub fn raw_other(&self) -> u8 {
+        self.visi as u8
+    }
+
+    /// The name demangled as a Rust or else a C++ symbol (without the
+    /// Rust hash as `readelf -C`), `None` if it's neither of them
+    #[cfg(feature = "demangle")]
+    pub fn demangled(&self) -> Option<String> {
+        if let Ok(sym) = rustc_demangle::try_demangle(&self.name) {
+            return Some(alloc::format!("{:#}", sym));
+        }
+
+        cpp_demangle::Symbol::new(self.name.as_bytes())
+            .ok()?
+            .demangle(&Default::default())
+            .ok()
+    }
+}
+
+impl SysvHashTable {
+    /// The SysV ELF hash function (`elf_hash`)
+    pub fn hash(name: &[u8]) -> u32 {
+        name.iter().fold(0u32, |h, &c| {
+            let h = (h << 4).wrapping_add(c as u32);
+            let g = h & 0xf000_0000;
+
+            (h ^ (g >> 24)) & !g
+        })
+    }
+
+    /// Index of the defined symbol `name` in `dynsym`, the table of this
+    /// hash table. As `ld.so`, the undefined ones on the chain are skipped.
+    pub fn lookup(&self, name: &str, dynsym: &SymTab) -> Option<usize> {
+        if self.buckets.is_empty() {
+            return None;
+        }
+
+        let hash = Self::hash(name.as_bytes());
+        let mut idx = self.buckets[hash as usize % self.buckets.len()];
+
+        /* A looping chain can't visit more than all the symbols */
+        for _ in 0..self.chain.len() {
+            if idx == 0 {
+                return None;
+            }
+
+            let sym = dynsym.get(idx as usize)?;
+
+            if sym.name == name && !matches!(sym.shndx, SID::Undef) {
+                return Some(idx as usize);
+            }
+
+            idx = *self.chain.get(idx as usize)?;
+        }
+
+        None
+    }
+}
+
+impl EhFrameHdr {
+    /// Address of the FDE which may cover `addr`, the one of the greatest
+    /// initial location not above `addr`.
+    ///
+    /// The end of the range is in the FDE itself, so it's only a candidate.
+    pub fn find_fde(&self, addr: u64) -> Option<Hex64> {
+        let i = self.table.partition_point(|(loc, _)| loc.0 <= addr);
+
+        i.checked_sub(1).map(|i| self.table[i].1)
+    }
+}
+
+impl CoreThread {
+    /// Value of the register `name`, e.g. `"rip"`
+    pub fn register(&self, name: &str) -> Option<u64> {
+        self.registers
+            .iter()
+            .find(|(reg, _)| *reg == name)
+            .map(|(_, val)| *val)
+    }
+}
+
+impl SymValue {
+    /// The raw value reinterpreted as signed,
+    /// e.g. an `SHN_ABS` symbol with a negative value
+    pub fn as_signed(&self) -> i64 {
+        match *self {
+            Self::Alignment(val) | Self::SectionOffset(val) => val as i64,
+            Self::VirAddr(val) => val.0 as i64,
+            Self::Undefined => 0,
+        }
+    }
 }
 
 impl SymVisi {
     pub fn load_from_other(other: u8) -> Self {
-        let val = other & 0x3;
+        match other & 0x3 {
+            0 => Self::Default,
+            1 => Self::Internal,
+            2 => Self::Hidden,
+            _ => Self::Protected,
+        }
+    }
+}
+
+impl SymTab {
+    pub fn get(&self, i: usize) -> Option<&SymView> {
+        self.0.get(i)
+    }
+
+    /// Symbols in the symbol table order, the null one (index 0) included
+    pub fn iter(&self) -> core::slice::Iter<'_, SymView> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Symbols of the type, e.g. only the functions
+    pub fn filter_by_type(
+        &self,
+        ty: SymType,
+    ) -> impl Iterator<Item = &SymView> + '_ {
+        self.0.iter().filter(move |sym| sym.ty == ty)
+    }
+}
+
+impl core::ops::Index<usize> for SymTab {
+    type Output = SymView;
+
+    fn index(&self, i: usize) -> &Self::Output {
+        &self.0[i]
+    }
+}
+
+impl<'a> IntoIterator for &'a SymTab {
+    type Item = &'a SymView;
+    type IntoIter = core::slice::Iter<'a, SymView>;
 
-        unsafe { std::mem::transmute(val) }
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
 }
 
 impl Debug for SymTab {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         writeln!(f)?;
         for (i, entry) in self.0.iter().enumerate() {
             writeln!(f, "{}: {:?}", i, entry)?;
@@ -0,0 +1,91 @@
+#!/usr/bin/env python3
+"""Craft the malformed inputs of the fuzz corpus from the draft fixtures.
+
+Run from the repository root: python3 tests/fuzz_corpus/gen.py
+"""
+import os
+import struct
+
+OUT = os.path.dirname(os.path.abspath(__file__))
+
+arr = open('draft/arr', 'rb').read()
+lib = open('draft/libarr.so.1', 'rb').read()
+
+
+def put(buf, off, fmt, val):
+    buf = bytearray(buf)
+    struct.pack_into('<' + fmt, buf, off, val)
+    return bytes(buf)
+
+
+def shdr(elf, idx):
+    shoff = struct.unpack_from('<Q', elf, 0x28)[0]
+    return shoff + idx * 64
+
+
+def section(elf, name):
+    shoff = struct.unpack_from('<Q', elf, 0x28)[0]
+    shnum, shstrndx = struct.unpack_from('<HH', elf, 0x3c)
+    strtab_off = struct.unpack_from('<Q', elf, shdr(elf, shstrndx) + 24)[0]
+    for idx in range(shnum):
+        name_off = struct.unpack_from('<I', elf, shoff + idx * 64)[0]
+        start = strtab_off + name_off
+        if elf[start:elf.index(b'\0', start)].decode() == name:
+            return shdr(elf, idx)
+    raise KeyError(name)
+
+
+corpus = {
+    'empty': b'',
+    'magic_only': b'\x7fELF',
+    'ident_only': arr[:16],
+    'header_only': arr[:64],
+    'truncated_in_shdrs': arr[:shdr(arr, 5) + 10],
+    'bad_class': put(arr, 4, 'B', 3),
+    'bad_data': put(arr, 5, 'B', 7),
+    'class_32': put(arr, 4, 'B', 1),
+    'huge_shnum': put(arr, 0x3c, 'H', 0xffff),
+    'huge_shoff': put(arr, 0x28, 'Q', 0xffff_ffff_ffff_fff0),
+    'zero_shentsize': put(arr, 0x3a, 'H', 0),
+    'huge_shentsize': put(arr, 0x3a, 'H', 0xffff),
+    'shstrndx_out_of_range': put(arr, 0x3e, 'H', 200),
+    'xindex_without_sections': put(put(arr, 0x3e, 'H', 0xffff), 0x3c, 'H', 0),
+    'sh_name_out_of_range': put(arr, shdr(arr, 1), 'I', 0xffff_ffff),
+    'symtab_offset_overflow':
+        put(arr, section(arr, '.symtab') + 24, 'Q', 0xffff_ffff_ffff_ff00),
+    'symtab_size_huge':
+        put(arr, section(arr, '.symtab') + 32, 'Q', 0x7fff_ffff_ffff_ffff),
+    'strtab_out_of_range':
+        put(arr, section(arr, '.strtab') + 24, 'Q', 0xffff_ffff_0000),
+    'huge_phnum': put(lib, 0x38, 'H', 0xffff),
+    'dynamic_size_huge':
+        put(lib, section(lib, '.dynamic') + 32, 'Q', 0xffff_ffff_ffff),
+    'verdef_count_huge':
+        put(lib, section(lib, '.gnu.version_d') + 44, 'I', 0xffff_ffff),
+    'gnu_hash_buckets_huge': put(
+        lib,
+        struct.unpack_from('<Q', lib, section(lib, '.gnu.hash') + 24)[0],
+        'I',
+        0xffff_ffff,
+    ),
+}
+
+# Out-of-range enums: e_type, e_machine, sh_type, sh_flags, st_info, st_other
+bad = put(put(arr, 0x10, 'H', 0x1234), 0x12, 'H', 0xbeef)
+bad = put(bad, shdr(arr, 1) + 4, 'I', 30)
+bad = put(bad, shdr(arr, 3) + 4, 'I', 0x6fff_1234)
+bad = put(bad, shdr(arr, 1) + 8, 'Q', 0xffff_ffff_ffff_ffff)
+symtab = struct.unpack_from('<Q', arr, section(arr, '.symtab') + 24)[0]
+bad = put(bad, symtab + 24 * 3 + 4, 'B', 0xff)
+bad = put(bad, symtab + 24 * 3 + 5, 'B', 0xff)
+corpus['out_of_range_enums'] = bad
+
+# Cyclic links: .symtab links to itself, .rela.text to itself
+cyclic = put(arr, section(arr, '.symtab') + 40, 'I', 9)
+cyclic = put(cyclic, section(arr, '.rela.text') + 40, 'I', 2)
+cyclic = put(cyclic, section(arr, '.rela.text') + 44, 'I', 2)
+corpus['cyclic_links'] = cyclic
+
+for name, data in corpus.items():
+    with open(os.path.join(OUT, name), 'wb') as f:
+        f.write(data)